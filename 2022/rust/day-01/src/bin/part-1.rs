@@ -1,7 +1,13 @@
-use day_01::process_input1;
-use std::fs;
+use aoc_core::{input, Error};
+use day_01::{process_input1, Part1};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
-    println!("Output part 01: {}", process_input1(file));
+fn main() -> Result<(), Error> {
+    let reader = input::open::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    let answer = process_input1(reader)?;
+    println!("Output part 01: {answer}");
+    if let Some(explanation) = answer.explanation {
+        println!("{explanation}");
+    }
+
+    Ok(())
 }
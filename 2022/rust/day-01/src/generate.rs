@@ -0,0 +1,43 @@
+use aoc_core::generate::Rng;
+
+/// the snacks of `size` elves (at least three), every elf carries one to fifteen of them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size.max(3))
+        .map(|_| {
+            (0..rng.range(1..=15))
+                .map(|_| format!("{}\n", rng.range(1_000..=70_000)))
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..50 {
+            let input = generate(&mut Rng::new(seed), 200);
+            let mut elves: Vec<usize> = input
+                .split("\n\n")
+                .map(|elf| elf.lines().map(|line| line.parse::<usize>().unwrap()).sum())
+                .collect();
+            elves.sort_unstable_by(|a, b| b.cmp(a));
+
+            assert_eq!(elves.len(), 200);
+            assert_eq!(
+                process_input1(input.as_bytes()).unwrap().to_string(),
+                elves[0].to_string(),
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input.as_bytes(), 3).unwrap().to_string(),
+                elves[..3].iter().sum::<usize>().to_string(),
+                "seed {seed}"
+            );
+        }
+    }
+}
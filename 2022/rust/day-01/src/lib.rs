@@ -1,48 +1,371 @@
-/// sum up all calories until empty line is reached, then add a new 0 and sum up further
-fn get_summed_elf_calories(input: String) -> Vec<usize> {
-    input.lines().fold(vec![0], |mut accum, calories_line| {
-        if calories_line.is_empty() {
-            accum.push(0);
-            return accum;
+mod generate;
+use aoc_core::{input::InputError, Answer, Error, Solution};
+use std::{
+    cmp::Reverse,
+    collections::BinaryHeap,
+    io::{BufRead, Lines},
+};
+
+pub use generate::generate;
+
+/// an elf and the snacks it carries, `index` counts the elves of the input from 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elf {
+    pub index: usize,
+    pub calories: usize,
+    pub items: usize,
+}
+
+/// Reads the elves of an input one at a time, an empty line ends an elf. The whole input is
+/// never held in memory, only the line being read.
+pub struct Elves<R> {
+    lines: Lines<R>,
+    line: usize,
+    index: usize,
+}
+
+impl<R: BufRead> Elves<R> {
+    pub fn new(reader: R) -> Self {
+        Elves {
+            lines: reader.lines(),
+            line: 0,
+            index: 0,
         }
-        let calories = calories_line.parse::<usize>().unwrap();
-        *accum.last_mut().unwrap() += calories;
+    }
+}
 
-        accum
-    })
+impl<R: BufRead> Iterator for Elves<R> {
+    type Item = Result<Elf, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut elf = Elf {
+            index: self.index,
+            calories: 0,
+            items: 0,
+        };
+
+        for line in self.lines.by_ref() {
+            let line = match line {
+                Ok(line) => line,
+                Err(err) => {
+                    return Some(Err(Error::Input(InputError::Stream {
+                        line: self.line,
+                        reason: err.to_string(),
+                    })))
+                }
+            };
+            self.line += 1;
+            let line = line.trim_end();
+            if line.is_empty() {
+                // more than one empty line between two elves do not make an elf without snacks
+                if elf.items == 0 {
+                    continue;
+                }
+                break;
+            }
+
+            let Ok(calories) = line.parse::<usize>() else {
+                return Some(Err(
+                    Error::expected(self.line, "the calories of a snack").at_column(1)
+                ));
+            };
+            let Some(carried) = elf.calories.checked_add(calories) else {
+                return Some(Err(Error::expected(
+                    self.line,
+                    format!("at most {} calories for an elf", usize::MAX),
+                )
+                .at_column(1)));
+            };
+            elf.calories = carried;
+            elf.items += 1;
+        }
+
+        if elf.items == 0 {
+            return None;
+        }
+        self.index += 1;
+        Some(Ok(elf))
+    }
 }
 
-pub fn process_input1(input: String) -> String {
-    let highest_elf = get_summed_elf_calories(input)
+/// The `count` elves carrying the most calories, most calories first. Elves with the same
+/// calories are ordered by their index. Only `count` elves are kept while reading, fewer are
+/// returned if the input has fewer elves.
+pub fn top_elves(reader: impl BufRead, count: usize) -> Result<Vec<Elf>, Error> {
+    // a min heap of the best elves so far, its root is the first to drop out
+    let mut top: BinaryHeap<Reverse<(usize, Reverse<usize>, usize)>> =
+        BinaryHeap::with_capacity(count + 1);
+
+    for elf in Elves::new(reader) {
+        let elf = elf?;
+        top.push(Reverse((elf.calories, Reverse(elf.index), elf.items)));
+        if top.len() > count {
+            top.pop();
+        }
+    }
+
+    Ok(top
+        .into_sorted_vec()
         .into_iter()
-        .enumerate()
-        .max_by(|(_, x), (_, y)| x.cmp(y))
-        .unwrap();
+        .map(|Reverse((calories, Reverse(index), items))| Elf {
+            index,
+            calories,
+            items,
+        })
+        .collect())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    /// every elf in the order of the input
+    pub elves: Vec<Elf>,
+    /// the mean calories carried by an elf
+    pub mean: f64,
+    /// the median calories carried by an elf, the mean of the two middle elves for an even count
+    pub median: f64,
+}
+
+/// every elf with its calories and snacks, the median needs all of them
+pub fn stats(reader: impl BufRead) -> Result<Stats, Error> {
+    let elves = Elves::new(reader).collect::<Result<Vec<_>, _>>()?;
+    if elves.is_empty() {
+        return Err(Error::Unsolvable(
+            "there are no elves in the input".to_string(),
+        ));
+    }
+
+    let mut calories: Vec<usize> = elves.iter().map(|elf| elf.calories).collect();
+    calories.sort_unstable();
+    let middle = calories.len() / 2;
+    let median = if calories.len().is_multiple_of(2) {
+        (calories[middle - 1] as f64 + calories[middle] as f64) / 2.0
+    } else {
+        calories[middle] as f64
+    };
 
-    format!(
+    // summed as floats, the calories of all elves may not fit into an usize
+    Ok(Stats {
+        mean: calories
+            .iter()
+            .map(|&calories| calories as f64)
+            .sum::<f64>()
+            / calories.len() as f64,
+        median,
+        elves,
+    })
+}
+
+pub fn process_input1(reader: impl BufRead) -> Result<Answer, Error> {
+    let highest_elf = top_elves(reader, 1)?
+        .pop()
+        .ok_or_else(|| Error::Unsolvable("there are no elves in the input".to_string()))?;
+
+    Ok(Answer::from(highest_elf.calories).explained(format!(
         "The elf nr. '{index1}' has carried a total of '{summedCalories}' calories",
-        index1 = highest_elf.0 + 1,
-        summedCalories = highest_elf.1
-    )
+        index1 = highest_elf.index + 1,
+        summedCalories = highest_elf.calories
+    )))
 }
 
-pub fn process_input2(input: String) -> String {
-    let mut elfes_desc: Vec<(usize, usize)> = get_summed_elf_calories(input)
-        .into_iter()
-        .enumerate()
-        .collect();
-
-    elfes_desc.sort_by(|(_, a), (_, b)| b.cmp(a));
-
-    let top_three_elfes = (elfes_desc[0], elfes_desc[1], elfes_desc[2]);
-
-    format!(
-        "Top three elf with the indexes {indexes:?} carried a summed total of {carried}",
-        indexes = vec![
-            top_three_elfes.0 .0,
-            top_three_elfes.1 .0,
-            top_three_elfes.2 .0
-        ],
-        carried = top_three_elfes.0 .1 + top_three_elfes.1 .1 + top_three_elfes.2 .1
-    )
+/// the calories carried by the `count` elves carrying the most of them
+pub fn process_input2(reader: impl BufRead, count: usize) -> Result<Answer, Error> {
+    let top_elves = top_elves(reader, count)?;
+    let carried = top_elves
+        .iter()
+        .try_fold(0usize, |carried, elf| carried.checked_add(elf.calories))
+        .ok_or_else(|| {
+            Error::Unsolvable(format!(
+                "the top {count} elves carry more than {} calories",
+                usize::MAX
+            ))
+        })?;
+
+    Ok(Answer::from(carried).explained(format!(
+        "The elves nr. {numbers:?} have carried a summed total of '{carried}' calories",
+        numbers = top_elves
+            .iter()
+            .map(|elf| elf.index + 1)
+            .collect::<Vec<_>>(),
+    )))
+}
+
+/// how many of the elves carrying the most calories are summed up
+pub struct Part2Params {
+    pub count: usize,
+}
+
+impl Default for Part2Params {
+    fn default() -> Self {
+        Self { count: 3 }
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = Answer;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.as_bytes())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+
+    type Params = Part2Params;
+    type Answer = Answer;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.as_bytes(), params.count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
+
+    #[test]
+    fn part_1() {
+        assert_examples::<Part1>(TASKS);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+
+    const EXAMPLE: &str = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+
+    #[test]
+    fn top_n() {
+        let top = top_elves(EXAMPLE.as_bytes(), 2).unwrap();
+        assert_eq!(
+            top,
+            vec![
+                Elf {
+                    index: 3,
+                    calories: 24000,
+                    items: 3
+                },
+                Elf {
+                    index: 2,
+                    calories: 11000,
+                    items: 2
+                }
+            ]
+        );
+
+        let all = top_elves(EXAMPLE.as_bytes(), 10).unwrap();
+        assert_eq!(
+            all.iter().map(|elf| elf.index).collect::<Vec<_>>(),
+            vec![3, 2, 4, 0, 1]
+        );
+        assert_eq!(top_elves(EXAMPLE.as_bytes(), 0), Ok(vec![]));
+        // the first of two elves carrying the same calories ranks higher
+        let tied = top_elves("5\n\n7\n\n3\n4\n".as_bytes(), 2).unwrap();
+        assert_eq!(tied.iter().map(|elf| elf.index).collect::<Vec<_>>(), [1, 2]);
+    }
+
+    #[test]
+    fn fewer_elves_than_the_top() {
+        assert_eq!(
+            Part2::solve("100\n\n200\n", &Part2Params::default()).map(|answer| answer.to_string()),
+            Ok("300".to_string())
+        );
+        assert_eq!(
+            Part2::solve(EXAMPLE, &Part2Params { count: 1 }).map(|answer| answer.to_string()),
+            Ok("24000".to_string())
+        );
+        assert!(matches!(Part1::solve("", &()), Err(Error::Unsolvable(_))));
+    }
+
+    #[test]
+    fn statistics() {
+        let stats = stats(EXAMPLE.as_bytes()).unwrap();
+        assert_eq!(
+            stats.elves.iter().map(|elf| elf.items).collect::<Vec<_>>(),
+            vec![3, 1, 2, 3, 1]
+        );
+        assert_eq!(stats.mean, 11000.0);
+        assert_eq!(stats.median, 10000.0);
+        assert_eq!(
+            super::stats("1\n\n2\n\n\n4\n\n8\n".as_bytes()).map(|stats| stats.median),
+            Ok(3.0)
+        );
+    }
+
+    #[test]
+    fn non_numeric_lines() {
+        assert_eq!(
+            top_elves("1000\n\n2000\nabc\n".as_bytes(), 3),
+            Err(Error::expected(4, "the calories of a snack").at_column(1))
+        );
+        assert_eq!(
+            Part1::solve("1000\n-5\n", &()).map_err(|err| err.to_string()),
+            Err("line 2, column 1: expected the calories of a snack".to_string())
+        );
+    }
+
+    #[test]
+    fn overflowing_calories() {
+        let elf = format!("{}\n1\n", usize::MAX);
+        assert_eq!(
+            top_elves(elf.as_bytes(), 1),
+            Err(
+                Error::expected(2, format!("at most {} calories for an elf", usize::MAX))
+                    .at_column(1)
+            )
+        );
+
+        let elves = format!("{}\n\n1\n", usize::MAX);
+        assert_eq!(
+            Part2::solve(&elves, &Part2Params::default()),
+            Err(Error::Unsolvable(format!(
+                "the top 3 elves carry more than {} calories",
+                usize::MAX
+            )))
+        );
+        let stats = stats(elves.as_bytes()).unwrap();
+        assert_eq!(stats.median, usize::MAX as f64 / 2.0);
+        assert_eq!(stats.mean, usize::MAX as f64 / 2.0);
+    }
+
+    #[test]
+    fn explanations_number_elves_from_1() {
+        let part1 = Part1::solve(EXAMPLE, &()).unwrap();
+        assert_eq!(
+            part1.explanation.as_deref(),
+            Some("The elf nr. '4' has carried a total of '24000' calories")
+        );
+        let part2 = Part2::solve(EXAMPLE, &Part2Params::default()).unwrap();
+        assert_eq!(
+            part2.explanation.as_deref(),
+            Some("The elves nr. [4, 3, 5] have carried a summed total of '45000' calories")
+        );
+    }
+
+    #[test]
+    fn unreadable_input() {
+        // the second line is not valid UTF-8
+        assert_eq!(
+            top_elves(&b"1000\n\xff\n"[..], 3),
+            Err(Error::Input(InputError::Stream {
+                line: 1,
+                reason: "stream did not contain valid UTF-8".to_string()
+            }))
+        );
+    }
 }
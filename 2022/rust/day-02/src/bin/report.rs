@@ -0,0 +1,19 @@
+use aoc_core::{input, Error};
+use day_02::{report, Columns, Decoder, Game, Part1, Strategy};
+
+/// prints every round of the strategy guide read both ways, with the score of each round
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    let game = Game::rock_paper_scissors();
+    let columns = Columns::default();
+
+    for (title, strategy) in [
+        ("the second column is my move", Strategy::Moves),
+        ("the second column is the outcome", Strategy::Outcomes),
+    ] {
+        let rounds = Decoder::new(&game, &columns, strategy)?.decode(&file)?;
+        println!("{title}:\n{}\n", report(&game, &rounds));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,257 @@
+use std::fmt::{self, Display, Formatter};
+
+/// how a round ended for the player whose move comes first
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    pub const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        };
+        f.pad(name)
+    }
+}
+
+/// a move of a [`Game`], the index of its row in the table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Move(pub usize);
+
+/// A game like rock, paper, scissors defined by a table: the moves with the score for playing
+/// them, which move beats which and the score of every outcome. Any two different moves must
+/// have exactly one winner, so every round has an outcome, and every move must beat a move and be
+/// beaten by one, so every outcome can be played against it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Game {
+    moves: Vec<(String, usize)>,
+    /// `beats[a][b]` is true if `a` beats `b`
+    beats: Vec<Vec<bool>>,
+    /// the scores of a loss, a draw and a win
+    outcome_scores: [usize; 3],
+}
+
+impl Game {
+    /// `moves` are the names of the moves with their scores, `beats` the pairs of (winner, loser)
+    pub fn new(
+        moves: &[(&str, usize)],
+        beats: &[(&str, &str)],
+        outcome_scores: [usize; 3],
+    ) -> Result<Self, String> {
+        let index = |name: &str| {
+            moves
+                .iter()
+                .position(|(move_name, _)| *move_name == name)
+                .ok_or_else(|| format!("`{name}` is not a move of the game"))
+        };
+        for (position, (name, _)) in moves.iter().enumerate() {
+            if index(name)? != position {
+                return Err(format!("the move `{name}` is defined twice"));
+            }
+        }
+
+        let mut table = vec![vec![false; moves.len()]; moves.len()];
+        for (winner, loser) in beats {
+            let (winner_index, loser_index) = (index(winner)?, index(loser)?);
+            if winner_index == loser_index {
+                return Err(format!("`{winner}` can not beat itself"));
+            }
+            if table[loser_index][winner_index] {
+                return Err(format!("`{winner}` and `{loser}` beat each other"));
+            }
+            table[winner_index][loser_index] = true;
+        }
+        for a in 0..moves.len() {
+            for b in a + 1..moves.len() {
+                if !table[a][b] && !table[b][a] {
+                    return Err(format!(
+                        "neither `{}` nor `{}` wins against the other",
+                        moves[a].0, moves[b].0
+                    ));
+                }
+            }
+        }
+        for (position, (name, _)) in moves.iter().enumerate() {
+            if !table[position].contains(&true) {
+                return Err(format!("`{name}` beats no move"));
+            }
+            if !table.iter().any(|row| row[position]) {
+                return Err(format!("no move beats `{name}`"));
+            }
+        }
+
+        Ok(Game {
+            moves: moves
+                .iter()
+                .map(|(name, score)| (name.to_string(), *score))
+                .collect(),
+            beats: table,
+            outcome_scores,
+        })
+    }
+
+    pub fn rock_paper_scissors() -> Self {
+        Game::new(
+            &[("Rock", 1), ("Paper", 2), ("Scissors", 3)],
+            &[
+                ("Rock", "Scissors"),
+                ("Paper", "Rock"),
+                ("Scissors", "Paper"),
+            ],
+            [0, 3, 6],
+        )
+        .expect("the table is complete")
+    }
+
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::new(
+            &[
+                ("Rock", 1),
+                ("Paper", 2),
+                ("Scissors", 3),
+                ("Lizard", 4),
+                ("Spock", 5),
+            ],
+            &[
+                ("Scissors", "Paper"),
+                ("Paper", "Rock"),
+                ("Rock", "Lizard"),
+                ("Lizard", "Spock"),
+                ("Spock", "Scissors"),
+                ("Scissors", "Lizard"),
+                ("Lizard", "Paper"),
+                ("Paper", "Spock"),
+                ("Spock", "Rock"),
+                ("Rock", "Scissors"),
+            ],
+            [0, 3, 6],
+        )
+        .expect("the table is complete")
+    }
+
+    pub fn moves(&self) -> impl Iterator<Item = Move> {
+        (0..self.moves.len()).map(Move)
+    }
+
+    pub fn name(&self, played: Move) -> &str {
+        &self.moves[played.0].0
+    }
+
+    pub fn outcome(&self, mine: Move, theirs: Move) -> Outcome {
+        if mine == theirs {
+            Outcome::Draw
+        } else if self.beats[mine.0][theirs.0] {
+            Outcome::Win
+        } else {
+            Outcome::Loss
+        }
+    }
+
+    pub fn move_score(&self, played: Move) -> usize {
+        self.moves[played.0].1
+    }
+
+    pub fn outcome_score(&self, outcome: Outcome) -> usize {
+        self.outcome_scores[outcome as usize]
+    }
+
+    /// the score of the move plus the score of the outcome
+    pub fn score(&self, mine: Move, theirs: Move) -> usize {
+        self.move_score(mine) + self.outcome_score(self.outcome(mine, theirs))
+    }
+
+    /// The move which ends the round against `theirs` with `outcome`. Games with more than three
+    /// moves have several, the one scoring the most is played.
+    pub fn move_for(&self, theirs: Move, outcome: Outcome) -> Move {
+        self.moves()
+            .filter(|mine| self.outcome(*mine, theirs) == outcome)
+            .max_by_key(|mine| self.move_score(*mine))
+            .expect("every move beats a move and is beaten by one")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors() {
+        let game = Game::rock_paper_scissors();
+        let (rock, paper, scissors) = (Move(0), Move(1), Move(2));
+        assert_eq!(game.outcome(paper, rock), Outcome::Win);
+        assert_eq!(game.outcome(rock, paper), Outcome::Loss);
+        assert_eq!(game.score(paper, rock), 8);
+        assert_eq!(game.score(rock, paper), 1);
+        assert_eq!(game.score(scissors, scissors), 6);
+        assert_eq!(game.move_for(rock, Outcome::Loss), scissors);
+        assert_eq!(game.move_for(rock, Outcome::Win), paper);
+    }
+
+    #[test]
+    fn lizard_spock() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        for theirs in game.moves() {
+            let wins = game
+                .moves()
+                .filter(|mine| game.outcome(*mine, theirs) == Outcome::Win)
+                .count();
+            assert_eq!(wins, 2, "{}", game.name(theirs));
+            for outcome in Outcome::ALL {
+                assert_eq!(
+                    game.outcome(game.move_for(theirs, outcome), theirs),
+                    outcome
+                );
+            }
+        }
+        // paper and spock beat rock, spock scores more
+        assert_eq!(game.name(game.move_for(Move(0), Outcome::Win)), "Spock");
+        assert_eq!(game.score(Move(3), Move(4)), 10);
+    }
+
+    #[test]
+    fn incomplete_tables() {
+        let moves = [("Rock", 1), ("Paper", 2), ("Scissors", 3)];
+        assert_eq!(
+            Game::new(
+                &moves,
+                &[("Rock", "Scissors"), ("Paper", "Rock")],
+                [0, 3, 6]
+            ),
+            Err("neither `Paper` nor `Scissors` wins against the other".to_string())
+        );
+        assert_eq!(
+            Game::new(&moves, &[("Rock", "Well")], [0, 3, 6]),
+            Err("`Well` is not a move of the game".to_string())
+        );
+        assert_eq!(
+            Game::new(&moves, &[("Rock", "Paper"), ("Paper", "Rock")], [0, 3, 6]),
+            Err("`Paper` and `Rock` beat each other".to_string())
+        );
+        // a complete table, but nothing beats rock and scissors beats nothing
+        assert_eq!(
+            Game::new(
+                &moves,
+                &[
+                    ("Rock", "Paper"),
+                    ("Rock", "Scissors"),
+                    ("Paper", "Scissors")
+                ],
+                [0, 3, 6]
+            ),
+            Err("no move beats `Rock`".to_string())
+        );
+        assert_eq!(
+            Game::new(&[("Rock", 1)], &[], [0, 3, 6]),
+            Err("`Rock` beats no move".to_string())
+        );
+    }
+}
@@ -1,140 +1,356 @@
-#[derive(Debug, PartialEq, PartialOrd)]
-enum PickType {
-    Rock = 1,
-    Paper = 2,
-    Scissores = 3,
-}
+mod game;
+use aoc_core::{column_of, Answer, Error, Solution};
 
-enum OutcomeScore {
-    Loss = 0,
-    Draw = 3,
-    Win = 6,
-}
+pub use game::{Game, Move, Outcome};
 
-#[derive(Debug)]
-struct Pick {
-    p_type: PickType,
+/// which letter of the strategy guide stands for what
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Columns {
+    /// the letters of the opponents moves, in the order of the moves of the game
+    pub theirs: Vec<char>,
+    /// the letters of the second column, my moves like `theirs` or a loss, a draw and a win
+    pub mine: Vec<char>,
 }
 
-impl Pick {
-    pub fn new(char: &str) -> Self {
-        Self {
-            p_type: Pick::char_to_pick_score(char),
+impl Columns {
+    pub fn new(theirs: &str, mine: &str) -> Self {
+        Columns {
+            theirs: theirs.chars().collect(),
+            mine: mine.chars().collect(),
         }
     }
+}
 
-    fn char_to_pick_score(char: &str) -> PickType {
-        match char {
-            "B" | "Y" => PickType::Paper,
-            "C" | "Z" => PickType::Scissores,
-            _ => PickType::Rock,
-        }
+impl Default for Columns {
+    fn default() -> Self {
+        Columns::new("ABC", "XYZ")
     }
+}
 
-    fn compare_to(&self, pick: &Pick) -> OutcomeScore {
-        if self.p_type == PickType::Rock {
-            return match pick.p_type {
-                PickType::Rock => OutcomeScore::Draw,
-                PickType::Paper => OutcomeScore::Loss,
-                PickType::Scissores => OutcomeScore::Win,
-            };
-        }
+/// what the second column of the strategy guide means
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Strategy {
+    /// the move to play, part 1
+    Moves,
+    /// how the round has to end, part 2
+    Outcomes,
+}
 
-        if self.p_type == PickType::Paper {
-            return match pick.p_type {
-                PickType::Rock => OutcomeScore::Win,
-                PickType::Paper => OutcomeScore::Draw,
-                PickType::Scissores => OutcomeScore::Loss,
-            };
-        }
+/// a decoded line of the strategy guide with its score
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Round {
+    pub line: usize,
+    pub theirs: Move,
+    pub mine: Move,
+    pub outcome: Outcome,
+    pub move_score: usize,
+    pub outcome_score: usize,
+}
+
+impl Round {
+    pub fn score(&self) -> usize {
+        self.move_score + self.outcome_score
+    }
+}
 
-        match pick.p_type {
-            PickType::Rock => OutcomeScore::Loss,
-            PickType::Paper => OutcomeScore::Win,
-            PickType::Scissores => OutcomeScore::Draw,
+/// Decodes a strategy guide strictly: every line is two letters of the columns separated by a
+/// space, anything else is an error on its line.
+pub struct Decoder<'a> {
+    game: &'a Game,
+    columns: &'a Columns,
+    strategy: Strategy,
+}
+
+impl<'a> Decoder<'a> {
+    /// fails if the columns do not have a distinct letter for every move (or outcome)
+    pub fn new(game: &'a Game, columns: &'a Columns, strategy: Strategy) -> Result<Self, Error> {
+        let mine_count = match strategy {
+            Strategy::Moves => game.moves().count(),
+            Strategy::Outcomes => Outcome::ALL.len(),
+        };
+        for (letters, count) in [
+            (&columns.theirs, game.moves().count()),
+            (&columns.mine, mine_count),
+        ] {
+            let distinct = letters
+                .iter()
+                .enumerate()
+                .all(|(index, letter)| !letters[..index].contains(letter));
+            if letters.len() != count || !distinct {
+                return Err(Error::Params(format!(
+                    "the column letters {letters:?} do not name {count} distinct moves or outcomes"
+                )));
+            }
         }
+
+        Ok(Decoder {
+            game,
+            columns,
+            strategy,
+        })
+    }
+
+    /// the index of `letter` in `letters`, `part` is the letter as it is in `line`
+    fn letter(line: &str, part: &str, letters: &[char], number: usize) -> Result<usize, Error> {
+        let mut chars = part.chars();
+        chars
+            .next()
+            .filter(|_| chars.next().is_none())
+            .and_then(|letter| letters.iter().position(|known| *known == letter))
+            .ok_or_else(|| {
+                let letters = letters
+                    .iter()
+                    .map(|letter| format!("`{letter}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                Error::expected(number, format!("one of {letters}"))
+                    .at_column(column_of(line, part))
+            })
     }
 
-    fn get_score(&self, pick: &Pick) -> usize {
-        let round_result = self.compare_to(pick);
+    pub fn decode_line(&self, line: &str, number: usize) -> Result<Round, Error> {
+        let (theirs, mine) = line
+            .split_once(' ')
+            .ok_or_else(|| Error::expected(number, "two letters separated by a space"))?;
+
+        let theirs = Move(Self::letter(line, theirs, &self.columns.theirs, number)?);
+        let mine_index = Self::letter(line, mine, &self.columns.mine, number)?;
+        let mine = match self.strategy {
+            Strategy::Moves => Move(mine_index),
+            Strategy::Outcomes => self.game.move_for(theirs, Outcome::ALL[mine_index]),
+        };
+
+        let outcome = self.game.outcome(mine, theirs);
+        Ok(Round {
+            line: number,
+            theirs,
+            mine,
+            outcome,
+            move_score: self.game.move_score(mine),
+            outcome_score: self.game.outcome_score(outcome),
+        })
+    }
 
-        round_result as usize + self.p_type as usize
+    pub fn decode(&self, input: &str) -> Result<Vec<Round>, Error> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| self.decode_line(line, index + 1))
+            .collect()
     }
 }
 
-fn get_line_picks(input: String) -> Vec<(Pick, Pick)> {
-    input
-        .lines()
-        .map(|line| {
-            let chars = line.split_once(' ').unwrap();
-            (Pick::new(chars.0), Pick::new(chars.1))
-        })
-        .collect()
+/// my total score for the strategy guide
+pub fn score(
+    game: &Game,
+    columns: &Columns,
+    strategy: Strategy,
+    input: &str,
+) -> Result<usize, Error> {
+    let rounds = Decoder::new(game, columns, strategy)?.decode(input)?;
+    Ok(rounds.iter().map(Round::score).sum())
 }
 
-fn get_score_sum(line_picks: Vec<(Pick, Pick)>) -> usize {
-    line_picks
-        .into_iter()
-        .fold(0, |mut accum, (pick_a, pick_b)| {
-            let score = pick_b.get_score(&pick_a);
-            accum += score;
-            accum
+/// every round with both moves, the outcome and how the score adds up, then the total
+pub fn report(game: &Game, rounds: &[Round]) -> String {
+    let width = game
+        .moves()
+        .map(|played| game.name(played).len())
+        .max()
+        .unwrap_or_default();
+
+    let mut lines: Vec<String> = rounds
+        .iter()
+        .map(|round| {
+            format!(
+                "line {:>5}  {:<width$} vs {:<width$}  {:<4}  {} + {} = {}",
+                round.line,
+                game.name(round.mine),
+                game.name(round.theirs),
+                round.outcome,
+                round.move_score,
+                round.outcome_score,
+                round.score()
+            )
         })
+        .collect();
+    lines.push(format!(
+        "total {}",
+        rounds.iter().map(Round::score).sum::<usize>()
+    ));
+    lines.join("\n")
 }
 
-pub fn process_input1(input: String) -> String {
-    let line_picks = get_line_picks(input);
-    let rounds_played = line_picks.len();
-    let score_sum = get_score_sum(line_picks);
+pub fn process_input1(input: String, columns: &Columns) -> Result<Answer, Error> {
+    let rounds_played = input.lines().count();
+    let score_sum = score(
+        &Game::rock_paper_scissors(),
+        columns,
+        Strategy::Moves,
+        &input,
+    )?;
 
-    format!(
-        "You played {rounds_played:?} of rock,paper,scissors against the elves!. Your score: {score_sum:?}", 
+    Ok(Answer::from(score_sum).explained(format!(
+        "You played {rounds_played:?} of rock,paper,scissors against the elves!. Your score: {score_sum:?}",
         rounds_played = rounds_played,
         score_sum = score_sum
-    )
+    )))
 }
 
-pub fn process_input2(input: String) -> String {
-    let line_picks: Vec<(Pick, Pick)> = input
-        .lines()
-        .map(|line| {
-            let chars = line.split_once(' ').unwrap();
-            let opponent_pick = Pick::new(chars.0);
-
-            let adjusted_pick = match chars.1 {
-                // Make a Draw
-                "Y" => match opponent_pick.p_type {
-                    PickType::Rock => "X",
-                    PickType::Paper => "Y",
-                    PickType::Scissores => "Z",
-                },
-                // Loose
-                "X" => match opponent_pick.p_type {
-                    PickType::Rock => "Z",
-                    PickType::Paper => "X",
-                    PickType::Scissores => "Y",
-                },
-                // Win
-                "Z" => match opponent_pick.p_type {
-                    PickType::Rock => "Y",
-                    PickType::Paper => "Z",
-                    PickType::Scissores => "X",
-                },
-                _ => {
-                    println!("unsupported character: {}", chars.1);
-                    "X"
-                }
-            };
-
-            (opponent_pick, Pick::new(adjusted_pick))
-        })
-        .collect();
-    let rounds_played = line_picks.len();
-    let score_sum = get_score_sum(line_picks);
+pub fn process_input2(input: String, columns: &Columns) -> Result<Answer, Error> {
+    let rounds_played = input.lines().count();
+    let score_sum = score(
+        &Game::rock_paper_scissors(),
+        columns,
+        Strategy::Outcomes,
+        &input,
+    )?;
+
+    let match_fixing = columns
+        .mine
+        .iter()
+        .zip(Outcome::ALL)
+        .map(|(letter, outcome)| format!("{letter}=={outcome}"))
+        .collect::<Vec<_>>()
+        .join(", ");
 
-    format!(
-        "You played {rounds_played:?} and followed the match-fixing of Y==Draw, X==Lose, Z==Win of rock,paper,scissors against the elves!. Your score: {score_sum:?}", 
+    Ok(Answer::from(score_sum).explained(format!(
+        "You played {rounds_played:?} and followed the match-fixing of {match_fixing} of rock,paper,scissors against the elves!. Your score: {score_sum:?}",
         rounds_played = rounds_played,
         score_sum = score_sum
-    )
+    )))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const PART: u8 = 1;
+
+    type Params = Columns;
+    type Answer = Answer;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string(), params)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+
+    type Params = Columns;
+    type Answer = Answer;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string(), params)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
+
+    #[test]
+    fn part_1() {
+        assert_examples::<Part1>(TASKS);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn other_games() {
+        let game = Game::rock_paper_scissors_lizard_spock();
+        let columns = Columns::new("ABCDE", "VWXYZ");
+        // spock against rock, lizard against paper and scissors against spock
+        assert_eq!(
+            score(&game, &columns, Strategy::Moves, "A Z\nB Y\nE X\n"),
+            Ok(11 + 10 + 3)
+        );
+        // spock beats rock, lizard beats paper and spock draws
+        let columns = Columns::new("ABCDE", "LDW");
+        assert_eq!(
+            score(&game, &columns, Strategy::Outcomes, "A W\nB W\nE D\n"),
+            Ok(11 + 10 + 8)
+        );
+    }
+
+    #[test]
+    fn strict_decoding() {
+        let game = Game::rock_paper_scissors();
+        let columns = Columns::default();
+        let decoder = Decoder::new(&game, &columns, Strategy::Moves).unwrap();
+        let decode = |input| decoder.decode(input);
+
+        assert_eq!(
+            decode("A Y\nB Q\n"),
+            Err(Error::expected(2, "one of `X`, `Y`, `Z`").at_column(3))
+        );
+        assert_eq!(
+            decode("D X\n"),
+            Err(Error::expected(1, "one of `A`, `B`, `C`").at_column(1))
+        );
+        assert_eq!(
+            decode("A Y\nC Z\nAY\n"),
+            Err(Error::expected(3, "two letters separated by a space"))
+        );
+        assert_eq!(
+            decode("A YY\n").map_err(|err| err.to_string()),
+            Err("line 1, column 3: expected one of `X`, `Y`, `Z`".to_string())
+        );
+        assert!(Part2::solve("A W\n", &Columns::default()).is_err());
+
+        let swapped = Columns::new("CBA", "ZYX");
+        assert_eq!(
+            Part1::solve("A Z\n", &swapped).map(|answer| answer.to_string()),
+            Ok("7".to_string())
+        );
+        assert_eq!(
+            Decoder::new(&game, &Columns::new("AAB", "XYZ"), Strategy::Moves).err(),
+            Some(Error::Params(
+                "the column letters ['A', 'A', 'B'] do not name 3 distinct moves or outcomes"
+                    .to_string()
+            ))
+        );
+        assert!(Decoder::new(&game, &Columns::new("ABC", "XY"), Strategy::Outcomes).is_err());
+    }
+
+    #[test]
+    fn match_fixing_of_the_columns() {
+        let answer = Part2::solve("A D\nB L\nC W\n", &Columns::new("ABC", "LDW")).unwrap();
+        assert_eq!(
+            answer.explanation.as_deref(),
+            Some("You played 3 and followed the match-fixing of L==loss, D==draw, W==win of rock,paper,scissors against the elves!. Your score: 12")
+        );
+    }
+
+    #[test]
+    fn round_report() {
+        let game = Game::rock_paper_scissors();
+        let columns = Columns::default();
+        let rounds = Decoder::new(&game, &columns, Strategy::Outcomes)
+            .unwrap()
+            .decode("A Y\nB X\nC Z\n")
+            .unwrap();
+        assert_eq!(
+            report(&game, &rounds),
+            [
+                "line     1  Rock     vs Rock      draw  1 + 3 = 4",
+                "line     2  Rock     vs Paper     loss  1 + 0 = 1",
+                "line     3  Rock     vs Scissors  win   1 + 6 = 7",
+                "total 12",
+            ]
+            .join("\n")
+        );
+    }
 }
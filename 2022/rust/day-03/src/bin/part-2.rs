@@ -1,7 +1,13 @@
-use day_03::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_03::{process_input2, Part2, Part2Params};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
-    println!("Output part 02: {:?}", process_input2(file));
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
+    let answer = process_input2(file, Part2Params::default().group_size)?;
+    println!("Output part 02: {answer}");
+    if let Some(explanation) = answer.explanation {
+        println!("{explanation}");
+    }
+
+    Ok(())
 }
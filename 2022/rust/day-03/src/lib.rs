@@ -1,91 +1,111 @@
-use std::collections::{HashMap, HashSet};
+mod rucksack;
+use aoc_core::{Answer, Error, Solution};
 
-/// creates a hashmap with each character in a string as key, and value = index+1
-/// @example create_item_types("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ") == HashMap{
-/// a: 1 , b: 2, c: 3 }
-pub fn create_item_types(chars: &str) -> HashMap<&str, usize> {
-    let char_iter = chars.split("");
+pub use rucksack::{common, group_badges, parse_rucksacks, priority, Items, Rucksack};
 
-    char_iter
-        .into_iter()
-        .filter(|char| !char.is_empty())
-        .enumerate()
-        .fold(
-            HashMap::new(),
-            |mut acc: HashMap<&str, usize>, (index, char)| {
-                acc.insert(char, index + 1);
-                acc
-            },
-        )
+/// priority sum of the components found in both compartments of each backpack
+fn get_compartments_priority(file: &str) -> Result<usize, Error> {
+    Ok(parse_rucksacks(file)?
+        .iter()
+        .map(|rucksack| rucksack.misplaced().priority_sum())
+        .sum())
 }
 
-/// Sums up all characters in the overlap Vec.
-/// @example get_overlap_sum(["a", "d"]) == 5
-pub fn get_overlap_sum(overlap: Vec<&str>) -> usize {
-    let item_types = create_item_types("abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ");
+pub fn process_input1(file: String) -> Result<Answer, Error> {
+    let overlap_sum = get_compartments_priority(&file)?;
+    Ok(Answer::from(overlap_sum).explained(format!(
+        "The priority sum of the overlapping backpack components in all compartments is {}",
+        overlap_sum
+    )))
+}
 
-    overlap
+/// priority sum of the components each group of `group_size` backpacks has in common
+fn get_groups_priority(file: &str, group_size: usize) -> Result<usize, Error> {
+    let rucksacks = parse_rucksacks(file)?;
+    Ok(group_badges(&rucksacks, group_size)?
         .into_iter()
-        .filter(|char| !char.is_empty())
-        .fold(0, |sum, overlapping| sum + item_types[overlapping])
+        .map(Items::priority_sum)
+        .sum())
 }
 
-/// takes a line, splits it in half and returns a unique vec of strings that are contained in each
-/// compartment.
-/// @exmaple get_overlap_line("vJrwpWtwJgWrhcsFMMfFFhFp") == ["p"]
-pub fn get_overlap_line(line: &str) -> Vec<&str> {
-    let compartments = line.split_at(line.len() / 2);
+pub fn process_input2(file: String, group_size: usize) -> Result<Answer, Error> {
+    let overlap_sum = get_groups_priority(&file, group_size)?;
+    Ok(Answer::from(overlap_sum).explained(format!(
+        "The priority sum of each groups unique overlapping components is {:?}",
+        overlap_sum
+    )))
+}
 
-    let overlapping_unique: HashSet<&str> = compartments
-        .0
-        .split("")
-        .filter(|char| compartments.1.contains(char))
-        .collect();
+/// how many elves make up a group
+pub struct Part2Params {
+    pub group_size: usize,
+}
 
-    overlapping_unique.into_iter().collect()
+impl Default for Part2Params {
+    fn default() -> Self {
+        Self { group_size: 3 }
+    }
 }
 
-/// takes a chunk of lines, and returns their overlapping chars
-/// @exmaple get_overlay_chunk(["vJrwpWtwJgWrhcsFMMfFFhFp", "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL", "PmmdzqPrVvPwwTWBwg"]) == ["r"]
-pub fn get_overlap_chunk(chunk: Vec<&str>) -> Vec<&str> {
-    let overlapping_unique: HashSet<&str> = chunk[0]
-        .split("")
-        .filter(|char| chunk[1].contains(char) && chunk[2].contains(char))
-        .collect();
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
 
-    overlapping_unique.into_iter().collect()
+    type Params = ();
+    type Answer = Answer;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
 }
 
-pub fn process_input1(file: String) -> String {
-    let overlap = file
-        .lines()
-        .fold(vec![], |mut overlapping_components: Vec<&str>, line| {
-            overlapping_components.append(&mut get_overlap_line(line));
-            overlapping_components
-        });
+pub struct Part2;
 
-    let overlap_sum = get_overlap_sum(overlap);
-    format!(
-        "The priority sum of the overlapping backpack components in all compartments is {}",
-        overlap_sum
-    )
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+
+    type Params = Part2Params;
+    type Answer = Answer;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string(), params.group_size)
+    }
 }
 
-pub fn process_input2(file: String) -> String {
-    let lines: Vec<&str> = file.lines().collect();
-    let chunks_of_three: Vec<&[&str]> = lines.chunks(3).collect();
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::tasks::assert_examples;
 
-    let overlap = chunks_of_three.iter().clone().fold(
-        vec![],
-        |mut overlapping_of_chunks: Vec<&str>, chunk| {
-            overlapping_of_chunks.append(&mut get_overlap_chunk(chunk.to_vec()));
-            overlapping_of_chunks
-        },
-    );
+    const TASKS: &str = include_str!("../../../tasks.md");
 
-    let overlap_sum = get_overlap_sum(overlap);
-    format!(
-        "The priority sum of each groups unique overlapping components is {:?}",
-        overlap_sum
-    )
+    #[test]
+    fn part_1() {
+        assert_examples::<Part1>(TASKS);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn other_group_sizes() {
+        let input = "vJrwpWtwJgWrhcsFMMfFFhFp\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n";
+        assert_eq!(
+            Part2::solve(input, &Part2Params { group_size: 2 }).map(|answer| answer.to_string()),
+            // the first two rucksacks of the example share r, s, f, F and M
+            Ok((18 + 19 + 6 + 32 + 39).to_string())
+        );
+        assert!(Part2::solve(input, &Part2Params::default()).is_err());
+        assert_eq!(
+            Part1::solve("vJrw\nab1c\n", &()),
+            Err(Error::expected(2, "an item from a to z or A to Z").at_column(3))
+        );
+    }
 }
@@ -0,0 +1,197 @@
+use aoc_core::Error;
+
+/// A set of item types, the item with priority `p` is bit `p - 1`. `a` to `z` have the
+/// priorities 1 to 26 and `A` to `Z` 27 to 52.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Items(u64);
+
+impl Items {
+    pub const ALL: Items = Items((1 << 52) - 1);
+
+    /// the items of `items`, which is `line` from its `column` on
+    pub fn parse(items: &str, line: usize, column: usize) -> Result<Self, Error> {
+        items
+            .bytes()
+            .enumerate()
+            .try_fold(Items::default(), |set, (index, item)| {
+                let priority = priority(item).ok_or_else(|| {
+                    Error::expected(line, "an item from a to z or A to Z").at_column(column + index)
+                })?;
+                Ok(Items(set.0 | 1 << (priority - 1)))
+            })
+    }
+
+    pub fn intersection(self, other: Items) -> Items {
+        Items(self.0 & other.0)
+    }
+
+    pub fn union(self, other: Items) -> Items {
+        Items(self.0 | other.0)
+    }
+
+    pub fn len(self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// the priorities of the items in the set, lowest first
+    pub fn priorities(self) -> impl Iterator<Item = usize> {
+        (1..=52).filter(move |priority| self.0 & 1 << (priority - 1) != 0)
+    }
+
+    pub fn priority_sum(self) -> usize {
+        self.priorities().sum()
+    }
+}
+
+/// the priority of an item type, `None` for anything but `a` to `z` and `A` to `Z`
+pub fn priority(item: u8) -> Option<usize> {
+    match item {
+        b'a'..=b'z' => Some(usize::from(item - b'a') + 1),
+        b'A'..=b'Z' => Some(usize::from(item - b'A') + 27),
+        _ => None,
+    }
+}
+
+/// the items every set has, none for no sets
+pub fn common(sets: impl IntoIterator<Item = Items>) -> Items {
+    let mut sets = sets.into_iter();
+    let first = sets.next().unwrap_or_default();
+    sets.fold(first, Items::intersection)
+}
+
+/// a line of the input, split into its two compartments of the same size
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rucksack {
+    pub compartments: [Items; 2],
+}
+
+impl Rucksack {
+    /// parses the `line`th line of the input
+    pub fn parse(items: &str, line: usize) -> Result<Self, Error> {
+        if items.is_empty() {
+            return Err(Error::expected(line, "the items of a rucksack"));
+        }
+        // the compartments are split by bytes, which are only items if they are ascii
+        if let Some(index) = items.chars().position(|item| !item.is_ascii()) {
+            return Err(Error::expected(line, "an item from a to z or A to Z").at_column(index + 1));
+        }
+        if !items.len().is_multiple_of(2) {
+            return Err(Error::expected(
+                line,
+                format!(
+                    "an even number of items for two compartments, got {}",
+                    items.len()
+                ),
+            ));
+        }
+
+        let (first, second) = items.split_at(items.len() / 2);
+        Ok(Rucksack {
+            compartments: [
+                Items::parse(first, line, 1)?,
+                Items::parse(second, line, first.len() + 1)?,
+            ],
+        })
+    }
+
+    /// every item type in the rucksack
+    pub fn items(&self) -> Items {
+        self.compartments[0].union(self.compartments[1])
+    }
+
+    /// the item types in both compartments
+    pub fn misplaced(&self) -> Items {
+        common(self.compartments)
+    }
+}
+
+pub fn parse_rucksacks(input: &str) -> Result<Vec<Rucksack>, Error> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| Rucksack::parse(line, index + 1))
+        .collect()
+}
+
+/// the item types the rucksacks of every group of `size` have in common, one set per group
+pub fn group_badges(rucksacks: &[Rucksack], size: usize) -> Result<Vec<Items>, Error> {
+    if size == 0 || !rucksacks.len().is_multiple_of(size) {
+        return Err(Error::Unsolvable(format!(
+            "{} rucksacks do not split into groups of {size}",
+            rucksacks.len()
+        )));
+    }
+
+    Ok(rucksacks
+        .chunks(size)
+        .map(|group| common(group.iter().map(Rucksack::items)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn item_sets() {
+        let items = Items::parse("vJrwpWtwJgWrhcsFMMfFFhFp", 1, 1).unwrap();
+        assert_eq!(items.len(), 14);
+        assert_eq!(priority(b'p'), Some(16));
+        assert_eq!(priority(b'L'), Some(38));
+        assert_eq!(priority(b'1'), None);
+
+        let both = common([
+            Items::parse("ad", 1, 1).unwrap(),
+            Items::parse("dAa", 1, 1).unwrap(),
+            Items::parse("Zda", 1, 1).unwrap(),
+        ]);
+        assert_eq!(both.priorities().collect::<Vec<_>>(), vec![1, 4]);
+        assert_eq!(both.priority_sum(), 5);
+        assert!(common([]).is_empty());
+        assert_eq!(Items::ALL.len(), 52);
+    }
+
+    #[test]
+    fn rucksacks() {
+        let rucksack = Rucksack::parse("vJrwpWtwJgWrhcsFMMfFFhFp", 1).unwrap();
+        assert_eq!(rucksack.misplaced().priority_sum(), 16);
+
+        assert_eq!(
+            Rucksack::parse("abc", 4),
+            Err(Error::expected(
+                4,
+                "an even number of items for two compartments, got 3"
+            ))
+        );
+        assert_eq!(
+            Rucksack::parse("abcd-f", 2),
+            Err(Error::expected(2, "an item from a to z or A to Z").at_column(5))
+        );
+        assert_eq!(
+            Rucksack::parse("", 7),
+            Err(Error::expected(7, "the items of a rucksack"))
+        );
+        // four bytes, the middle one is inside of the `é`
+        assert_eq!(
+            Rucksack::parse("aéb", 3),
+            Err(Error::expected(3, "an item from a to z or A to Z").at_column(2))
+        );
+    }
+
+    #[test]
+    fn groups() {
+        let rucksacks = parse_rucksacks("abcd\nbcde\ncdef\ncxyz\n").unwrap();
+        let badges = group_badges(&rucksacks, 2).unwrap();
+        assert_eq!(
+            badges.iter().map(|badge| badge.len()).collect::<Vec<_>>(),
+            vec![3, 1]
+        );
+        assert_eq!(group_badges(&rucksacks, 4).unwrap()[0].priority_sum(), 3);
+        assert!(group_badges(&rucksacks, 3).is_err());
+        assert!(group_badges(&rucksacks, 0).is_err());
+    }
+}
@@ -1,10 +1,18 @@
-use day_04::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_04::{get_coverage, process_input2, Part2};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
+    let coverage = get_coverage(&file)?;
     println!(
         "Output part 02: There are {:?} assignment pairs with overlap",
-        process_input2(file)
+        process_input2(file)?
     );
+    println!(
+        "The elves cover {} sections, {} of them are covered by a single elf",
+        coverage.covered.len(),
+        coverage.covered_once.len()
+    );
+
+    Ok(())
 }
@@ -0,0 +1,342 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The whole numbers `start..=end`, like the sections `2-4` of an elf. An interval is never
+/// empty, operations which could end up empty return an `Option` or an [`IntervalSet`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Interval {
+    start: i64,
+    end: i64,
+}
+
+impl Interval {
+    /// `None` if `start` is after `end`
+    pub fn new(start: i64, end: i64) -> Option<Self> {
+        (start <= end).then_some(Interval { start, end })
+    }
+
+    pub fn start(&self) -> i64 {
+        self.start
+    }
+
+    pub fn end(&self) -> i64 {
+        self.end
+    }
+
+    /// the amount of numbers in the interval, at least one. All of `i64` are `2^64` numbers, one
+    /// more than an `u64` holds.
+    pub fn size(&self) -> u128 {
+        u128::from(self.end.abs_diff(self.start)) + 1
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    /// true if every number of `other` is in `self`
+    pub fn contains_interval(&self, other: &Interval) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    pub fn overlaps(&self, other: &Interval) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    /// the numbers in both, `None` if they do not overlap
+    pub fn intersection(&self, other: &Interval) -> Option<Interval> {
+        Interval::new(self.start.max(other.start), self.end.min(other.end))
+    }
+
+    /// the numbers in either as a single interval, `None` if there is a gap between them.
+    /// Intervals which touch like `2-4` and `5-6` have no gap.
+    pub fn union(&self, other: &Interval) -> Option<Interval> {
+        let (first, second) = if self.start <= other.start {
+            (self, other)
+        } else {
+            (other, self)
+        };
+        // nothing comes after an interval ending at `i64::MAX`, so there is no gap
+        let touches = first
+            .end
+            .checked_add(1)
+            .is_none_or(|after| second.start <= after);
+        touches
+            .then(|| Interval::new(first.start, first.end.max(second.end)))
+            .flatten()
+    }
+
+    /// the numbers of `self` which are not in `other`, up to two intervals
+    pub fn difference(&self, other: &Interval) -> IntervalSet {
+        if !self.overlaps(other) {
+            return IntervalSet::from_iter([*self]);
+        }
+        // there is no piece before `other` if it starts at `i64::MIN`, none after it if it ends at
+        // `i64::MAX`
+        [
+            other
+                .start
+                .checked_sub(1)
+                .and_then(|end| Interval::new(self.start, end)),
+            other
+                .end
+                .checked_add(1)
+                .and_then(|start| Interval::new(start, self.end)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect()
+    }
+}
+
+impl Display for Interval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Any numbers as sorted intervals, which neither overlap nor touch. Adding an interval merges
+/// it with the ones it overlaps or touches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct IntervalSet {
+    intervals: Vec<Interval>,
+}
+
+impl IntervalSet {
+    pub fn new() -> Self {
+        IntervalSet::default()
+    }
+
+    pub fn intervals(&self) -> &[Interval] {
+        &self.intervals
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// the amount of numbers in the set, the intervals are disjoint so it is at most `2^64`
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::size).sum()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        // the last interval starting at or before `value`
+        let index = self
+            .intervals
+            .partition_point(|interval| interval.start <= value);
+        index > 0 && self.intervals[index - 1].contains(value)
+    }
+
+    pub fn insert(&mut self, interval: Interval) {
+        let mut merged = interval;
+        self.intervals
+            .retain(|existing| match merged.union(existing) {
+                Some(union) => {
+                    merged = union;
+                    false
+                }
+                None => true,
+            });
+        let index = self
+            .intervals
+            .partition_point(|existing| existing.start < merged.start);
+        self.intervals.insert(index, merged);
+    }
+
+    pub fn union(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .chain(&other.intervals)
+            .copied()
+            .collect()
+    }
+
+    pub fn intersection(&self, other: &IntervalSet) -> IntervalSet {
+        self.intervals
+            .iter()
+            .flat_map(|interval| {
+                other
+                    .intervals
+                    .iter()
+                    .filter_map(|other| interval.intersection(other))
+            })
+            .collect()
+    }
+
+    pub fn difference(&self, other: &IntervalSet) -> IntervalSet {
+        other.intervals.iter().fold(self.clone(), |rest, removed| {
+            rest.intervals
+                .iter()
+                .flat_map(|interval| interval.difference(removed).intervals)
+                .collect()
+        })
+    }
+}
+
+impl FromIterator<Interval> for IntervalSet {
+    fn from_iter<I: IntoIterator<Item = Interval>>(intervals: I) -> Self {
+        let mut intervals: Vec<Interval> = intervals.into_iter().collect();
+        intervals.sort();
+
+        let mut merged: Vec<Interval> = Vec::with_capacity(intervals.len());
+        for interval in intervals {
+            match merged.last().and_then(|last| last.union(&interval)) {
+                Some(union) => *merged.last_mut().expect("there is a last interval") = union,
+                None => merged.push(interval),
+            }
+        }
+        IntervalSet { intervals: merged }
+    }
+}
+
+/// The numbers covered by exactly `times` of the `intervals`. Walks the starts and ends of the
+/// intervals in order, so it takes `n log n` for `n` intervals.
+pub fn covered_times(intervals: &[Interval], times: usize) -> IntervalSet {
+    // +1 where an interval starts and -1 after it ends, intervals ending at `i64::MAX` never end
+    let mut changes: Vec<(i64, isize)> = intervals
+        .iter()
+        .flat_map(|interval| {
+            let end = interval.end.checked_add(1).map(|after| (after, -1));
+            [Some((interval.start, 1)), end]
+        })
+        .flatten()
+        .collect();
+    changes.sort();
+
+    let mut covered = IntervalSet::new();
+    let mut count = 0;
+    for (index, (at, change)) in changes.iter().enumerate() {
+        count += change;
+        let end = match changes.get(index + 1) {
+            Some((next, _)) => next.checked_sub(1),
+            // the intervals still counted after the last change run up to `i64::MAX`
+            None if count > 0 => Some(i64::MAX),
+            None => None,
+        };
+        if count == times as isize {
+            if let Some(interval) = end.and_then(|end| Interval::new(*at, end)) {
+                covered.insert(interval);
+            }
+        }
+    }
+    covered
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interval(start: i64, end: i64) -> Interval {
+        Interval::new(start, end).unwrap()
+    }
+
+    fn set(intervals: &[(i64, i64)]) -> IntervalSet {
+        intervals
+            .iter()
+            .map(|(start, end)| interval(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn intervals() {
+        assert_eq!(Interval::new(4, 2), None);
+        assert_eq!(interval(2, 4).size(), 3);
+        assert_eq!(interval(6, 6).size(), 1);
+        assert!(interval(2, 8).contains_interval(&interval(3, 7)));
+        assert!(!interval(3, 7).contains_interval(&interval(2, 8)));
+        assert!(interval(5, 7).overlaps(&interval(7, 9)));
+        assert!(!interval(2, 4).overlaps(&interval(5, 6)));
+        assert_eq!(
+            interval(5, 7).intersection(&interval(7, 9)),
+            Some(interval(7, 7))
+        );
+        assert_eq!(interval(2, 4).intersection(&interval(6, 8)), None);
+        assert_eq!(interval(2, 4).union(&interval(5, 6)), Some(interval(2, 6)));
+        assert_eq!(interval(6, 8).union(&interval(2, 4)), None);
+        assert_eq!(
+            interval(2, 8).difference(&interval(4, 5)),
+            set(&[(2, 3), (6, 8)])
+        );
+        assert_eq!(
+            interval(2, 8).difference(&interval(1, 9)),
+            IntervalSet::new()
+        );
+        assert_eq!(interval(2, 8).difference(&interval(6, 12)), set(&[(2, 5)]));
+        assert_eq!(interval(2, 4).to_string(), "2-4");
+    }
+
+    #[test]
+    fn bounds() {
+        let (min, max) = (i64::MIN, i64::MAX);
+        assert_eq!(
+            interval(5, max).difference(&interval(0, max)),
+            IntervalSet::new()
+        );
+        assert_eq!(
+            interval(min, 0).difference(&interval(min, -5)),
+            set(&[(-4, 0)])
+        );
+        assert_eq!(
+            interval(min, max).difference(&interval(0, 0)),
+            set(&[(min, -1), (1, max)])
+        );
+        assert_eq!(interval(min, max).size(), 1 << 64);
+        assert_eq!(interval(min, -1).size(), 1 << 63);
+        assert_eq!(set(&[(min, -1), (1, max)]).len(), (1 << 64) - 1);
+        assert_eq!(
+            interval(0, max).union(&interval(max, max)),
+            Some(interval(0, max))
+        );
+        assert_eq!(
+            interval(min, min).union(&interval(min + 1, 3)),
+            Some(interval(min, 3))
+        );
+
+        let intervals = [interval(0, max), interval(1, 2), interval(min, min)];
+        assert_eq!(
+            covered_times(&intervals, 1),
+            set(&[(min, min), (0, 0), (3, max)])
+        );
+        assert_eq!(covered_times(&intervals, 2), set(&[(1, 2)]));
+        assert_eq!(
+            covered_times(&[interval(min, min), interval(min, min)], 2),
+            set(&[(min, min)])
+        );
+    }
+
+    #[test]
+    fn interval_sets() {
+        let sets = set(&[(6, 8), (2, 4), (3, 5), (10, 10)]);
+        assert_eq!(sets.intervals(), &[interval(2, 8), interval(10, 10)]);
+        assert_eq!(sets.len(), 8);
+        assert!(sets.contains(10));
+        assert!(!sets.contains(9));
+        assert!(!sets.contains(1));
+
+        let mut inserted = set(&[(1, 2), (5, 6), (9, 9)]);
+        inserted.insert(interval(3, 5));
+        assert_eq!(inserted, set(&[(1, 6), (9, 9)]));
+
+        let other = set(&[(4, 11)]);
+        assert_eq!(sets.union(&other), set(&[(2, 11)]));
+        assert_eq!(sets.intersection(&other), set(&[(4, 8), (10, 10)]));
+        assert_eq!(sets.difference(&other), set(&[(2, 3)]));
+        assert_eq!(other.difference(&sets), set(&[(9, 9), (11, 11)]));
+    }
+
+    #[test]
+    fn coverage() {
+        let intervals = [
+            interval(2, 4),
+            interval(3, 6),
+            interval(4, 8),
+            interval(10, 10),
+        ];
+        assert_eq!(
+            covered_times(&intervals, 1),
+            set(&[(2, 2), (7, 8), (10, 10)])
+        );
+        assert_eq!(covered_times(&intervals, 2), set(&[(3, 3), (5, 6)]));
+        assert_eq!(covered_times(&intervals, 3), set(&[(4, 4)]));
+        assert_eq!(covered_times(&[], 1), IntervalSet::new());
+    }
+}
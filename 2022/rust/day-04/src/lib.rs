@@ -1,86 +1,162 @@
-use std::{num::ParseIntError, ops::Range, str::FromStr};
+mod interval;
+use aoc_core::{Error, Solution};
+use aoc_parse::{lines, parse_all, tag, unsigned, IResult};
+use nom::{combinator::map_opt, error::context, sequence::separated_pair};
+
+pub use interval::{covered_times, Interval, IntervalSet};
 
 #[derive(Debug)]
 struct Assignment {
-    sections: Range<i32>,
+    sections: Interval,
 }
 
-impl FromStr for Assignment {
-    type Err = ParseIntError;
+/// takes a range in the form of "4-8" to return the sections 4 to 8
+fn parse_assignment(input: &str) -> IResult<'_, Assignment> {
+    context(
+        "sections `start-end` with the start not after the end",
+        map_opt(
+            separated_pair(unsigned, tag("-"), unsigned),
+            |(start, end)| Interval::new(start, end),
+        ),
+    )(input)
+    .map(|(input, sections)| (input, Assignment { sections }))
+}
 
-    // takes a range in the form of "4-8" to return a range (4..8)
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (left, right) = s.split_once('-').unwrap();
+/// "2-4,6-8"
+fn parse_assignment_pair(input: &str) -> IResult<'_, (Assignment, Assignment)> {
+    separated_pair(parse_assignment, tag(","), parse_assignment)(input)
+}
 
-        let left_fromstr = left.parse::<i32>()?;
-        let right_fromstr = right.parse::<i32>()?;
+fn get_assignment_pairs(file: &str) -> Result<Vec<(Assignment, Assignment)>, Error> {
+    parse_all(file, lines(parse_assignment_pair))
+}
 
-        Ok(Assignment {
-            sections: (left_fromstr..right_fromstr),
-        })
-    }
+fn assignments_fully_contained((first, second): &(Assignment, Assignment)) -> bool {
+    first.sections.contains_interval(&second.sections)
+        || second.sections.contains_interval(&first.sections)
+}
+
+fn assignments_with_overlap((first, second): &(Assignment, Assignment)) -> bool {
+    first.sections.overlaps(&second.sections)
 }
 
-fn get_assignment_tuple(line: &str) -> (Assignment, Assignment) {
-    let (left, right) = line.split_once(',').unwrap();
+pub fn process_input1(file: String) -> Result<usize, Error> {
+    Ok(get_assignment_pairs(&file)?
+        .iter()
+        .filter(|pair| assignments_fully_contained(pair))
+        .count())
+}
 
-    (
-        Assignment::from_str(left).unwrap(),
-        Assignment::from_str(right).unwrap(),
-    )
+pub fn process_input2(file: String) -> Result<usize, Error> {
+    Ok(get_assignment_pairs(&file)?
+        .iter()
+        .filter(|pair| assignments_with_overlap(pair))
+        .count())
 }
 
-fn assignments_fully_contained(assignments: &(Assignment, Assignment)) -> bool {
-    assignments.0.sections.start >= assignments.1.sections.start
-        && assignments.0.sections.end <= assignments.1.sections.end
-        || assignments.1.sections.start >= assignments.0.sections.start
-            && assignments.1.sections.end <= assignments.0.sections.end
+/// how the sections of the camp are covered by the assignments of every elf
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Coverage {
+    /// the sections at least one elf is assigned to
+    pub covered: IntervalSet,
+    /// the sections exactly one elf is assigned to
+    pub covered_once: IntervalSet,
 }
 
-fn assignments_with_overlap(assignments: &(Assignment, Assignment)) -> bool {
-    assignments.0.sections.start <= assignments.1.sections.end
-        && assignments.0.sections.end >= assignments.1.sections.start
+pub fn get_coverage(file: &str) -> Result<Coverage, Error> {
+    let sections: Vec<Interval> = get_assignment_pairs(file)?
+        .into_iter()
+        .flat_map(|(first, second)| [first.sections, second.sections])
+        .collect();
+
+    Ok(Coverage {
+        covered: sections.iter().copied().collect(),
+        covered_once: covered_times(&sections, 1),
+    })
 }
 
-pub fn process_input1(file: String) -> usize {
-    file.trim()
-        .lines()
-        .map(get_assignment_tuple)
-        .filter(assignments_fully_contained)
-        .count()
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
 }
 
-pub fn process_input2(file: String) -> usize {
-    file.trim()
-        .lines()
-        .map(get_assignment_tuple)
-        .filter(assignments_with_overlap)
-        .count()
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file_input: String = String::from(concat!(
-            "2-4,6-8\n",
-            "2-3,4-5\n",
-            "5-7,7-9\n",
-            "2-8,3-7\n",
-            "6-6,4-6\n",
-            "2-6,4-8\n",
-        ));
-        let result = process_input1(file_input);
-        assert_eq!(result, 2);
+        assert_examples::<Part1>(TASKS);
     }
 
     #[test]
     fn part_2() {
-        let file_input: String =
-            String::from(concat!("5-7,7-9\n", "2-8,3-7\n", "6-6,4-6\n", "2-6,4-8\n",));
-        let result = process_input2(file_input);
-        assert_eq!(result, 4);
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn coverage() {
+        let coverage =
+            get_coverage("2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n").unwrap();
+        assert_eq!(
+            coverage.covered.intervals(),
+            &[Interval::new(2, 9).unwrap()]
+        );
+        assert_eq!(coverage.covered.len(), 8);
+        assert_eq!(coverage.covered_once.len(), 1);
+        assert!(coverage.covered_once.contains(9));
+
+        let coverage = get_coverage("0-9223372036854775807,1-2\n").unwrap();
+        assert_eq!(coverage.covered.len(), 1 << 63);
+        assert_eq!(
+            coverage.covered_once.intervals(),
+            &[
+                Interval::new(0, 0).unwrap(),
+                Interval::new(3, i64::MAX).unwrap()
+            ]
+        );
+    }
+
+    #[test]
+    fn reversed_assignment() {
+        let error = process_input2("2-4,6-8\n2-3,5-4\n".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 5: expected sections `start-end` with the start not after the end"
+        );
+    }
+
+    #[test]
+    fn malformed_assignment() {
+        let error = process_input1("2-4,6-8\n2-3;4-5\n".to_string()).unwrap_err();
+        assert_eq!(error.to_string(), "line 2, column 4: expected `,`");
     }
 }
@@ -0,0 +1,99 @@
+use aoc_core::generate::Rng;
+
+/// `[amount, from, to]` with the stacks numbered from 1
+type Move = [usize; 3];
+
+/// three to nine stacks of up to eight crates and `size` moves, which never take more crates
+/// from a stack than it holds
+fn stacks_and_moves(rng: &mut Rng, size: usize) -> (Vec<Vec<char>>, Vec<Move>) {
+    let crate_name = |rng: &mut Rng| (b'A' + rng.below(26) as u8) as char;
+    let stacks: Vec<Vec<char>> = (0..rng.range(3..=9))
+        .map(|_| (0..rng.range(1..=8)).map(|_| crate_name(rng)).collect())
+        .collect();
+
+    let mut heights: Vec<usize> = stacks.iter().map(Vec::len).collect();
+    let moves = (0..size)
+        .map(|_| {
+            let filled: Vec<usize> = (0..heights.len()).filter(|&i| heights[i] > 0).collect();
+            let from = *rng.pick(&filled);
+            let to = (from + 1 + rng.below(heights.len() - 1)) % heights.len();
+            let amount = 1 + rng.below(heights[from].min(5));
+
+            heights[from] -= amount;
+            heights[to] += amount;
+            [amount, from + 1, to + 1]
+        })
+        .collect();
+
+    (stacks, moves)
+}
+
+/// the drawing of the stacks with the numbers below them, an empty line and the moves
+fn draw(stacks: &[Vec<char>], moves: &[Move]) -> String {
+    let height = stacks.iter().map(Vec::len).max().unwrap_or_default();
+    let mut text = String::new();
+
+    for row in (0..height).rev() {
+        let crates: Vec<String> = stacks
+            .iter()
+            .map(|stack| match stack.get(row) {
+                Some(name) => format!("[{name}]"),
+                None => "   ".to_string(),
+            })
+            .collect();
+        text += &format!("{}\n", crates.join(" "));
+    }
+    let numbers: Vec<String> = (1..=stacks.len())
+        .map(|number| format!(" {number} "))
+        .collect();
+    text += &format!("{}\n\n", numbers.join(" "));
+
+    for [amount, from, to] in moves {
+        text += &format!("move {amount} from {from} to {to}\n");
+    }
+    text
+}
+
+/// crate stacks and `size` moves of them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let (stacks, moves) = stacks_and_moves(rng, size);
+    draw(&stacks, &moves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    /// the top crates after the moves, with the crates moved one by one or all at once
+    fn top_crates(mut stacks: Vec<Vec<char>>, moves: &[Move], one_by_one: bool) -> String {
+        for [amount, from, to] in moves {
+            let from = &mut stacks[from - 1];
+            let mut moved = from.split_off(from.len() - amount);
+            if one_by_one {
+                moved.reverse();
+            }
+            stacks[to - 1].append(&mut moved);
+        }
+        stacks.iter().filter_map(|stack| stack.last()).collect()
+    }
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..50 {
+            let (stacks, moves) = stacks_and_moves(&mut Rng::new(seed), 100);
+            let input = draw(&stacks, &moves);
+
+            assert_eq!(
+                process_input1(input.clone()),
+                Ok(top_crates(stacks.clone(), &moves, true)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input),
+                Ok(top_crates(stacks, &moves, false)),
+                "seed {seed}"
+            );
+        }
+    }
+}
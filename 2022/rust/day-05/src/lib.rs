@@ -1,4 +1,14 @@
-use std::{collections::HashMap, num::ParseIntError, str::FromStr};
+mod generate;
+use aoc_core::{Error, Solution};
+use aoc_parse::{lines, parse_all, tag, unsigned, IResult};
+use nom::{
+    bytes::complete::take_until,
+    error::context,
+    sequence::{terminated, tuple},
+};
+use std::collections::HashMap;
+
+pub use generate::generate;
 
 type Cargo = HashMap<usize, Vec<char>>;
 
@@ -7,69 +17,107 @@ pub struct Move {
     amount: usize,
     from: usize,
     to: usize,
+    /// the line of the move in the input
+    line: usize,
 }
 
-impl FromStr for Move {
-    type Err = ParseIntError;
-
-    // takes a move as move (n) from (m) to (p)
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let replace_instructions = s
-            .replace("move ", "")
-            .replace("from ", "")
-            .replace("to ", "");
-        let move_parts: Vec<_> = replace_instructions.split(' ').collect();
+/// takes a move as move (n) from (m) to (p)
+fn parse_move(input: &str) -> IResult<'_, Move> {
+    let (input, (_, amount, _, from, _, to)) = tuple((
+        tag("move "),
+        unsigned,
+        tag(" from "),
+        unsigned,
+        tag(" to "),
+        unsigned,
+    ))(input)?;
+    Ok((
+        input,
+        Move {
+            amount,
+            from,
+            to,
+            line: 0,
+        },
+    ))
+}
 
-        let amount = move_parts[0].parse::<usize>()?;
-        let from = move_parts[1].parse::<usize>()?;
-        let to = move_parts[2].parse::<usize>()?;
+impl Move {
+    /// takes the crates off the `from` stack, fails if a stack is not in the drawing or has fewer
+    /// crates than are moved
+    fn take(&self, cargo: &mut Cargo) -> Result<Vec<char>, Error> {
+        if !cargo.contains_key(&self.to) {
+            return Err(Error::expected(
+                self.line,
+                format!("a stack of the drawing, not {}", self.to),
+            ));
+        }
+        let from = cargo.get_mut(&self.from).ok_or_else(|| {
+            Error::expected(
+                self.line,
+                format!("a stack of the drawing, not {}", self.from),
+            )
+        })?;
 
-        Ok(Move { amount, from, to })
+        let rest = from.len().checked_sub(self.amount).ok_or_else(|| {
+            Error::expected(
+                self.line,
+                format!(
+                    "at most the {} crates of stack {}, not {}",
+                    from.len(),
+                    self.from,
+                    self.amount
+                ),
+            )
+        })?;
+        Ok(from.split_off(rest))
     }
-}
 
-impl Move {
-    fn apply_to_9000(&self, cargo: &mut Cargo) {
-        let from = cargo.get_mut(&self.from).unwrap();
+    fn put(&self, cargo: &mut Cargo, mut removed_elements: Vec<char>) {
+        cargo
+            .get_mut(&self.to)
+            .expect("take checked the stack")
+            .append(&mut removed_elements);
+    }
 
-        let mut removed_elements = from.split_off(from.len() - self.amount);
+    fn apply_to_9000(&self, cargo: &mut Cargo) -> Result<(), Error> {
+        let mut removed_elements = self.take(cargo)?;
         removed_elements.reverse();
 
-        let to = cargo.get_mut(&self.to).unwrap();
-        to.append(&mut removed_elements);
+        self.put(cargo, removed_elements);
+        Ok(())
     }
 
-    fn apply_to_9001(&self, cargo: &mut Cargo) {
-        let from = cargo.get_mut(&self.from).unwrap();
+    fn apply_to_9001(&self, cargo: &mut Cargo) -> Result<(), Error> {
+        let removed_elements = self.take(cargo)?;
 
-        let mut removed_elements = from.split_off(from.len() - self.amount);
-
-        let to = cargo.get_mut(&self.to).unwrap();
-        to.append(&mut removed_elements);
+        self.put(cargo, removed_elements);
+        Ok(())
     }
 }
 
 pub fn create_cargo_from_str(s: &str) -> Cargo {
     let crates = s.lines().rev().fold(Cargo::new(), |mut cargo, line| {
+        // the numbers below the stacks, a stack may start without crates
         if line.contains('1') {
+            for stack_nr in line.split_whitespace().filter_map(|nr| nr.parse().ok()) {
+                cargo.insert(stack_nr, vec![]);
+            }
             return cargo;
         }
 
         let mut stack_nr = 1;
-        line.replace("/n", "")
-            .chars()
-            .enumerate()
-            .for_each(|(index, char)| {
-                let is_4th_char_from_2nd = (2 + (index + 1)) % 4 == 0;
-
-                if is_4th_char_from_2nd {
-                    if char != ' ' {
-                        let stack = cargo.entry(stack_nr).or_insert(vec![]);
-                        stack.push(char);
-                    }
-                    stack_nr += 1;
+        line.chars().enumerate().for_each(|(index, char)| {
+            let is_4th_char_from_2nd = (2 + (index + 1)) % 4 == 0;
+
+            if is_4th_char_from_2nd {
+                if char != ' ' {
+                    let stack = cargo.entry(stack_nr).or_insert(vec![]);
+                    stack.push(char);
                 }
-            });
+                stack_nr += 1;
+            }
+        });
 
         cargo
     });
@@ -77,10 +125,24 @@ pub fn create_cargo_from_str(s: &str) -> Cargo {
     crates
 }
 
-pub fn create_moves_from_str(s: &str) -> Vec<Move> {
-    s.lines()
-        .map(|line| Move::from_str(line).unwrap())
-        .collect()
+/// the cargo drawing, an empty line and the moves below it
+fn parse_cargo_and_moves(input: &str) -> IResult<'_, (Cargo, Vec<Move>)> {
+    let (input, drawing) = context(
+        "an empty line below the drawing",
+        terminated(take_until("\n\n"), tag("\n\n")),
+    )(input)?;
+    let (input, mut moves) = lines(parse_move)(input)?;
+
+    // the drawing and the empty line below it come before the first move
+    let first_line = drawing.lines().count() + 2;
+    for (index, mv) in moves.iter_mut().enumerate() {
+        mv.line = first_line + index;
+    }
+    Ok((input, (create_cargo_from_str(drawing), moves)))
+}
+
+fn split_cargo_and_moves(file: &str) -> Result<(Cargo, Vec<Move>), Error> {
+    parse_all(file, parse_cargo_and_moves)
 }
 
 pub fn get_last_from_cargo_stacks(cargo: &Cargo) -> String {
@@ -99,69 +161,100 @@ pub fn get_last_from_cargo_stacks(cargo: &Cargo) -> String {
         })
 }
 
-pub fn process_input1(file: String) -> String {
-    let first_move_index: usize = file.find("move").unwrap();
-    let (cargo_str, moves_str) = file.split_at(first_move_index);
+pub fn process_input1(file: String) -> Result<String, Error> {
+    let (mut cargo, moves) = split_cargo_and_moves(&file)?;
 
-    let mut cargo = create_cargo_from_str(cargo_str);
-    let moves = create_moves_from_str(moves_str);
+    for mv in moves {
+        mv.apply_to_9000(&mut cargo)?;
+    }
 
-    moves.into_iter().for_each(|mv| {
-        mv.apply_to_9000(&mut cargo);
-    });
+    Ok(get_last_from_cargo_stacks(&cargo))
+}
+
+pub fn process_input2(file: String) -> Result<String, Error> {
+    let (mut cargo, moves) = split_cargo_and_moves(&file)?;
+
+    for mv in moves {
+        mv.apply_to_9001(&mut cargo)?;
+    }
 
-    get_last_from_cargo_stacks(&cargo)
+    Ok(get_last_from_cargo_stacks(&cargo))
 }
 
-pub fn process_input2(file: String) -> String {
-    let first_move_index: usize = file.find("move").unwrap();
-    let (cargo_str, moves_str) = file.split_at(first_move_index);
+pub struct Part1;
 
-    let mut cargo = create_cargo_from_str(cargo_str);
-    let moves = create_moves_from_str(moves_str);
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const PART: u8 = 1;
 
-    moves.into_iter().for_each(|mv| {
-        mv.apply_to_9001(&mut cargo);
-    });
+    type Params = ();
+    type Answer = String;
 
-    get_last_from_cargo_stacks(&cargo)
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file_input: String = String::from(concat!(
-            "    [D]    \n",
-            "[N] [C]    \n",
-            "[Z] [M] [P]\n",
-            " 1   2   3 \n",
-            "/n",
-            "move 1 from 2 to 1\n",
-            "move 3 from 1 to 3\n",
-            "move 2 from 2 to 1\n",
-            "move 1 from 1 to 2\n"
-        ));
-        let result = process_input1(file_input);
-        assert_eq!(result, "CMZ");
+        assert_examples::<Part1>(TASKS);
     }
 
     #[test]
     fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn malformed_move() {
         let file_input: String = String::from(concat!(
             "    [D]    \n",
             "[N] [C]    \n",
             "[Z] [M] [P]\n",
             " 1   2   3 \n",
-            "/n",
+            "\n",
             "move 1 from 2 to 1\n",
-            "move 3 from 1 to 3\n",
-            "move 2 from 2 to 1\n",
-            "move 1 from 1 to 2\n"
+            "move 3 from 1\n",
         ));
-        let result = process_input2(file_input);
-        assert_eq!(result, "MCD");
+        let error = process_input1(file_input).unwrap_err();
+        assert_eq!(error.to_string(), "line 7, column 14: expected ` to `");
+    }
+
+    #[test]
+    fn impossible_moves() {
+        let drawing = concat!("    [D]    \n", "[N] [C]    \n", " 1   2   3 \n", "\n");
+        let error = process_input1(format!("{drawing}move 1 from 2 to 3\nmove 1 from 2 to 4\n"));
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 6: expected a stack of the drawing, not 4"
+        );
+        let error = process_input2(format!("{drawing}move 3 from 1 to 2\n"));
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 5: expected at most the 1 crates of stack 1, not 3"
+        );
     }
 }
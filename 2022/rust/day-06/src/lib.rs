@@ -1,3 +1,4 @@
+use aoc_core::{Error, Solution};
 use std::collections::BTreeSet; // basically same as HahsSet but ordered
 
 /// gets the last chars index of a substring in file with district_chars amount of characters.
@@ -24,39 +25,50 @@ pub fn process_input2(file: String) -> usize {
     get_unique_index(14, file)
 }
 
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        Ok(process_input1(input.to_string()))
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        Ok(process_input2(input.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file_input: Vec<(&str, usize)> = vec![
-            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 7),
-            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 5),
-            ("nppdvjthqldpwncqszvftbrmjlhg", 6),
-            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 10),
-            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 11),
-        ];
-
-        file_input.iter().for_each(|(input, exresult)| {
-            let result = process_input1(input.to_string());
-            assert_eq!(result, *exresult);
-        });
+        assert_examples::<Part1>(TASKS);
     }
 
     #[test]
     fn part_2() {
-        let file_input: Vec<(&str, usize)> = vec![
-            ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", 19),
-            ("bvwbjplbgvbhsrlpgdmjqwftvncz", 23),
-            ("nppdvjthqldpwncqszvftbrmjlhg", 23),
-            ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", 29),
-            ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", 26),
-        ];
-
-        file_input.iter().for_each(|(input, exresult)| {
-            let result = process_input2(input.to_string());
-            assert_eq!(result, *exresult);
-        });
+        assert_examples::<Part2>(TASKS);
     }
 }
@@ -0,0 +1,143 @@
+use aoc_core::generate::Rng;
+
+/// directory 0 is `/`, the others have a name derived from their index
+struct Tree {
+    dirs: Vec<Vec<usize>>,
+    files: Vec<Vec<u32>>,
+}
+
+/// a name of lowercase letters for every index, `a`, `b`, ..., `ba`
+fn name(mut index: usize) -> String {
+    let mut name = vec![];
+    loop {
+        name.push(b'a' + (index % 26) as u8);
+        index /= 26;
+        if index == 0 {
+            break;
+        }
+    }
+    name.reverse();
+    String::from_utf8(name).expect("the name is made of letters")
+}
+
+/// `size` files in about a third as many directories. The files take up between 40M and 70M,
+/// so there is a directory to delete in part 2.
+fn tree(rng: &mut Rng, size: usize) -> Tree {
+    let size = size.max(1);
+    let dir_count = 1 + size / 3;
+    let mut dirs = vec![vec![]; dir_count];
+    for dir in 1..dir_count {
+        dirs[rng.below(dir)].push(dir);
+    }
+
+    // `ls` prints at least one entry, so the empty directories get a file first
+    let mut owners: Vec<usize> = (0..dir_count).filter(|&dir| dirs[dir].is_empty()).collect();
+    while owners.len() < size {
+        owners.push(rng.below(dir_count));
+    }
+
+    let weights: Vec<u64> = owners
+        .iter()
+        .map(|_| {
+            // a few tiny files, so some directories stay below 100k
+            if rng.chance(20) {
+                rng.range(1..=20) as u64
+            } else {
+                rng.range(100..=1_000) as u64
+            }
+        })
+        .collect();
+    let total_weight: u64 = weights.iter().sum();
+    let total_size = rng.range(41_000_000..=69_000_000) as u64;
+
+    let mut files = vec![vec![]; dir_count];
+    for (owner, weight) in owners.into_iter().zip(weights) {
+        files[owner].push((total_size * weight / total_weight).max(1) as u32);
+    }
+    Tree { dirs, files }
+}
+
+fn transcript(rng: &mut Rng, tree: &Tree, dir: usize, text: &mut String) {
+    *text += "$ ls\n";
+    let mut entries: Vec<String> = tree.dirs[dir]
+        .iter()
+        .map(|&child| format!("dir {}", name(child)))
+        .chain(tree.files[dir].iter().enumerate().map(|(index, size)| {
+            format!(
+                "{size} {}.{}",
+                name(index),
+                rng.pick(&["txt", "dat", "log"])
+            )
+        }))
+        .collect();
+    rng.shuffle(&mut entries);
+    for entry in entries {
+        *text += &format!("{entry}\n");
+    }
+
+    for &child in &tree.dirs[dir] {
+        *text += &format!("$ cd {}\n", name(child));
+        transcript(rng, tree, child, text);
+        *text += "$ cd ..\n";
+    }
+}
+
+/// the terminal output of browsing a filesystem with `size` files
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let tree = tree(rng, size);
+    let mut text = "$ cd /\n".to_string();
+    transcript(rng, &tree, 0, &mut text);
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    fn dir_sizes(tree: &Tree, dir: usize, sizes: &mut Vec<u32>) -> u32 {
+        let size = tree.files[dir].iter().sum::<u32>()
+            + tree.dirs[dir]
+                .iter()
+                .map(|&child| dir_sizes(tree, child, sizes))
+                .sum::<u32>();
+        sizes.push(size);
+        size
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(name(0), "a");
+        assert_eq!(name(25), "z");
+        assert_eq!(name(27), "bb");
+    }
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..50 {
+            let mut rng = Rng::new(seed);
+            let tree = tree(&mut rng, 300);
+            let mut input = "$ cd /\n".to_string();
+            transcript(&mut rng, &tree, 0, &mut input);
+
+            let mut sizes = vec![];
+            let used = dir_sizes(&tree, 0, &mut sizes);
+            let required = used - 40_000_000;
+
+            assert_eq!(
+                process_input1(input.clone()).unwrap(),
+                sizes.iter().filter(|&&size| size <= 100_000).sum::<u32>(),
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input).unwrap(),
+                sizes
+                    .into_iter()
+                    .filter(|&size| size > required)
+                    .min()
+                    .unwrap(),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -1,16 +1,21 @@
+mod generate;
 mod parser;
 mod tree;
+use aoc_core::{Error, Solution};
+use aoc_parse::parse_all;
 use parser::{parse_commands, Command, TreeStruct};
 use std::cell::RefCell;
 use std::rc::Rc;
 use tree::Node;
 
+pub use generate::generate;
+
 pub struct FileTree<'a> {
     root: Rc<RefCell<Node<TreeStruct<'a>>>>,
 }
 
-pub fn create_file_tree(input: &str) -> FileTree {
-    let (_, commands): (&str, Vec<Command>) = parse_commands(input).unwrap();
+pub fn create_file_tree(input: &str) -> Result<FileTree<'_>, Error> {
+    let commands: Vec<Command> = parse_all(input, parse_commands)?;
 
     let file_tree = FileTree {
         root: Rc::new(RefCell::new(Node::new(TreeStruct::Dir("/"), None))),
@@ -31,9 +36,10 @@ pub fn create_file_tree(input: &str) -> FileTree {
                     let parent_node = current_dir_node
                         .borrow_mut()
                         .parent_node
-                        .as_ref()
-                        .unwrap()
-                        .clone();
+                        .clone()
+                        .ok_or_else(|| {
+                            Error::Unsolvable("`$ cd ..` in the root directory".to_string())
+                        })?;
 
                     current_dir_node = parent_node;
                 }
@@ -51,7 +57,7 @@ pub fn create_file_tree(input: &str) -> FileTree {
         };
     }
 
-    file_tree
+    Ok(file_tree)
 }
 
 // recursivly gets the sum of the file-size size of the node and its children.
@@ -80,8 +86,8 @@ pub fn get_all_nodes(
     }
 }
 
-pub fn process_input1(file: String) -> u32 {
-    let file_tree = create_file_tree(&file);
+pub fn process_input1(file: String) -> Result<u32, Error> {
+    let file_tree = create_file_tree(&file)?;
 
     let mut sum = 0;
     get_all_nodes(file_tree.root, &mut |node| {
@@ -94,16 +100,18 @@ pub fn process_input1(file: String) -> u32 {
         }
     });
 
-    sum
+    Ok(sum)
 }
 
-pub fn process_input2(file: String) -> u32 {
-    let file_tree = create_file_tree(&file);
+pub fn process_input2(file: String) -> Result<u32, Error> {
+    let file_tree = create_file_tree(&file)?;
 
     const TOTAL_SIZE: u32 = 70000000;
     const NEEDED_SPACE: u32 = 30000000;
-    let free_space = TOTAL_SIZE - get_size(file_tree.root.clone());
-    let required_space = NEEDED_SPACE - free_space;
+    let free_space = TOTAL_SIZE
+        .checked_sub(get_size(file_tree.root.clone()))
+        .ok_or_else(|| Error::Unsolvable("the files do not fit on the disk".to_string()))?;
+    let required_space = NEEDED_SPACE.saturating_sub(free_space);
     let mut delete_candidate = u32::MAX;
 
     get_all_nodes(file_tree.root, &mut |node| {
@@ -116,22 +124,66 @@ pub fn process_input2(file: String) -> u32 {
         }
     });
 
-    delete_candidate
+    Ok(delete_candidate)
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = u32;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = u32;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string()), 95437);
+        assert_examples::<Part1>(TASKS);
     }
 
     #[test]
     fn part_2() {
-        let file = include_str!("test.txt");
-        assert_eq!(process_input2(file.to_string()), 24933642);
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn malformed_commands() {
+        let error = process_input1("$ cd /\n$ ls\ndir a\n12 b.txt\n$ rm b.txt\n".to_string());
+        assert_eq!(
+            error.unwrap_err().to_string(),
+            "line 5, column 1: expected `$ cd DIR` or `$ ls`"
+        );
+        assert!(matches!(
+            process_input1("$ cd /\n$ cd ..\n".to_string()),
+            Err(Error::Unsolvable(_))
+        ));
     }
 }
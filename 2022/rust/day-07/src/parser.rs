@@ -1,10 +1,11 @@
+use aoc_parse::{lines, tag, unsigned, IResult};
 use nom::{
     branch::alt,
-    bytes::complete::{is_a, tag},
-    character::complete::{alpha1, newline, u32},
+    bytes::complete::is_a,
+    character::complete::{alpha1, line_ending},
+    error::context,
     multi::separated_list1,
-    sequence::separated_pair,
-    IResult,
+    sequence::{preceded, separated_pair},
 };
 
 #[derive(Debug)]
@@ -19,32 +20,33 @@ pub enum TreeStruct<'a> {
     File((u32, &'a str)),
 }
 
-pub fn parse_file(input: &str) -> IResult<&str, TreeStruct> {
+pub fn parse_file(input: &str) -> IResult<'_, TreeStruct<'_>> {
     let (input, (file_size, file_name)) =
-        separated_pair(u32, tag(" "), is_a("qwertyuiopasdfghjklzxcvbnm."))(input)?;
+        separated_pair(unsigned, tag(" "), is_a("qwertyuiopasdfghjklzxcvbnm."))(input)?;
     Ok((input, TreeStruct::File((file_size, file_name))))
 }
 
-pub fn parse_dir(input: &str) -> IResult<&str, TreeStruct> {
-    let (input, _) = tag("dir ")(input)?;
-    let (input, dir_name) = alpha1(input)?;
+pub fn parse_dir(input: &str) -> IResult<'_, TreeStruct<'_>> {
+    let (input, dir_name) = preceded(tag("dir "), alpha1)(input)?;
     Ok((input, TreeStruct::Dir(dir_name)))
 }
 
-pub fn parse_ls(input: &str) -> IResult<&str, Command> {
+pub fn parse_ls(input: &str) -> IResult<'_, Command<'_>> {
     let (input, _) = tag("$ ls")(input)?;
-    let (input, _) = newline(input)?;
-    let (input, files) = separated_list1(newline, alt((parse_file, parse_dir)))(input)?;
+    let (input, _) = line_ending(input)?;
+    let (input, files) = context(
+        "a file like `1234 a.txt` or a directory like `dir a`",
+        separated_list1(line_ending, alt((parse_file, parse_dir))),
+    )(input)?;
     Ok((input, Command::Ls(files)))
 }
 
-pub fn parse_cd(input: &str) -> IResult<&str, Command> {
-    let (input, _) = tag("$ cd ")(input)?;
-    let (input, dir_name) = alt((tag(".."), tag("/"), alpha1))(input)?;
+pub fn parse_cd(input: &str) -> IResult<'_, Command<'_>> {
+    let (input, dir_name) = preceded(tag("$ cd "), alt((tag(".."), tag("/"), alpha1)))(input)?;
     Ok((input, Command::Cd(dir_name)))
 }
 
-pub fn parse_commands(input: &str) -> IResult<&str, Vec<Command>> {
-    let (input, commands) = separated_list1(newline, alt((parse_ls, parse_cd)))(input)?;
-    Ok((input, commands))
+/// the commands with their output, one command or output line per line
+pub fn parse_commands(input: &str) -> IResult<'_, Vec<Command<'_>>> {
+    lines(context("`$ cd DIR` or `$ ls`", alt((parse_ls, parse_cd))))(input)
 }
@@ -1,7 +1,12 @@
-use day_08::process_input1;
-use std::fs;
+use aoc_core::{input, Error};
+use day_08::{process_input1, Part1};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
-    println!("Output part 01: visible trees: {:?} ", process_input1(file));
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    println!(
+        "Output part 01: visible trees: {:?} ",
+        process_input1(file)?
+    );
+
+    Ok(())
 }
@@ -1,10 +1,12 @@
-use day_08::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_08::{process_input2, Part2};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 02: highest tree scenic score: {:?} ",
-        process_input2(file)
+        process_input2(file)?
     );
+
+    Ok(())
 }
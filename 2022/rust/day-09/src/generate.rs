@@ -0,0 +1,69 @@
+use aoc_core::generate::Rng;
+
+/// `size` moves of the head like `R 4`, one to twenty steps each
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    (0..size)
+        .map(|_| {
+            format!(
+                "{} {}\n",
+                rng.pick(&['U', 'D', 'L', 'R']),
+                rng.range(1..=20)
+            )
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    /// the positions the tail of a rope of `knots` visits
+    fn tail_positions(input: &str, knots: usize) -> usize {
+        let mut rope = vec![(0_i64, 0_i64); knots];
+        let mut visited = HashSet::from([(0, 0)]);
+
+        for line in input.lines() {
+            let (direction, steps) = line.split_once(' ').unwrap();
+            let (dx, dy) = match direction {
+                "U" => (0, 1),
+                "D" => (0, -1),
+                "L" => (-1, 0),
+                _ => (1, 0),
+            };
+            for _ in 0..steps.parse::<usize>().unwrap() {
+                rope[0] = (rope[0].0 + dx, rope[0].1 + dy);
+                for knot in 1..knots {
+                    let (x, y) = (
+                        rope[knot - 1].0 - rope[knot].0,
+                        rope[knot - 1].1 - rope[knot].1,
+                    );
+                    if x.abs() > 1 || y.abs() > 1 {
+                        rope[knot] = (rope[knot].0 + x.signum(), rope[knot].1 + y.signum());
+                    }
+                }
+                visited.insert(rope[knots - 1]);
+            }
+        }
+        visited.len()
+    }
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..50 {
+            let input = generate(&mut Rng::new(seed), 500);
+            assert_eq!(
+                process_input1(input.clone()),
+                Ok(tail_positions(&input, 2)),
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input.clone()),
+                Ok(tail_positions(&input, 10)),
+                "seed {seed}"
+            );
+        }
+    }
+}
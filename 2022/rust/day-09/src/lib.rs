@@ -1,6 +1,14 @@
+mod generate;
+use aoc_core::{Error, Solution};
+use aoc_parse::{lines, parse_all, tag, unsigned, IResult};
 use itertools::Itertools;
+use nom::{
+    character::complete::anychar, combinator::map_opt, error::context, sequence::separated_pair,
+};
 use std::collections::HashSet;
 
+pub use generate::generate;
+
 #[derive(Clone, Debug)]
 pub enum Direction {
     Up,
@@ -49,7 +57,8 @@ impl Rope {
         ]
     }
 
-    fn move_direction(&mut self, direction: &Direction) {
+    /// fails if a knot is too far from the knot before it to follow
+    fn move_direction(&mut self, direction: &Direction) -> Result<(), Error> {
         for index in 0..self.knots.len() {
             if index == 0 {
                 // move the head
@@ -123,14 +132,16 @@ impl Rope {
                         // x k x
                         // x x x
                     }
-                    _ => panic!(
-                        "unhandled cartesian overlap {}",
-                        overlapping_cartesian_positions.len()
-                    ),
+                    _ => {
+                        return Err(Error::Unsolvable(format!(
+                            "the knot at {current_knot:?} can not follow the knot at {prev_knot:?}"
+                        )))
+                    }
                 }
                 self.knots[index] = current_knot;
             }
         }
+        Ok(())
     }
 
     fn get_tail(&self) -> (i32, i32) {
@@ -138,67 +149,127 @@ impl Rope {
     }
 }
 
-pub fn get_moves(file: String) -> Vec<Direction> {
-    file.lines()
+fn parse_direction(input: &str) -> IResult<'_, Direction> {
+    context(
+        "one of `U`, `D`, `L` or `R`",
+        map_opt(anychar, |char| match char {
+            'U' => Some(Direction::Up),
+            'D' => Some(Direction::Down),
+            'L' => Some(Direction::Left),
+            'R' => Some(Direction::Right),
+            _ => None,
+        }),
+    )(input)
+}
+
+/// a move like `R 4`
+fn parse_move(input: &str) -> IResult<'_, (Direction, usize)> {
+    separated_pair(parse_direction, tag(" "), unsigned)(input)
+}
+
+pub fn get_moves(file: String) -> Result<Vec<Direction>, Error> {
+    let moves = parse_all(&file, lines(parse_move))?;
+
+    Ok(moves
         .into_iter()
-        .map(|line| {
-            let (direction, amount) = line.split_once(' ').unwrap();
-            let amount: usize = amount.parse().unwrap();
-            let direction: Direction = match direction {
-                "U" => Direction::Up,
-                "D" => Direction::Down,
-                "L" => Direction::Left,
-                "R" => Direction::Right,
-                _ => panic!("unhandled direction"),
-            };
-
-            (direction, amount)
-        })
         .flat_map(|(direction, amount)| vec![direction; amount])
-        .collect()
+        .collect())
 }
 
-pub fn process_input1(file: String) -> usize {
-    let moves = get_moves(file);
+pub fn process_input1(file: String) -> Result<usize, Error> {
+    let moves = get_moves(file)?;
 
     let mut rope = Rope::new(2);
     let mut unique_tail_positions = HashSet::from([rope.get_tail()]);
 
     for head_move in moves.iter() {
-        rope.move_direction(head_move);
+        rope.move_direction(head_move)?;
         unique_tail_positions.insert(rope.get_tail());
     }
 
-    unique_tail_positions.len()
+    Ok(unique_tail_positions.len())
 }
 
-pub fn process_input2(file: String) -> usize {
-    let moves = get_moves(file);
+pub fn process_input2(file: String) -> Result<usize, Error> {
+    let moves = get_moves(file)?;
 
     let mut rope = Rope::new(10);
     let mut unique_tail_positions = HashSet::from([rope.get_tail()]);
 
     for head_move in moves.iter() {
-        rope.move_direction(head_move);
+        rope.move_direction(head_move)?;
         unique_tail_positions.insert(rope.get_tail());
     }
 
-    unique_tail_positions.len()
+    Ok(unique_tail_positions.len())
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string()), 13);
+        assert_examples::<Part1>(TASKS);
     }
 
     #[test]
     fn part_2() {
-        let file = include_str!("test2.txt");
-        assert_eq!(process_input2(file.to_string()), 36);
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn unhandled_direction() {
+        let error = get_moves("R 4\nU 4\nX 3".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected one of `U`, `D`, `L` or `R`"
+        );
+    }
+
+    #[test]
+    fn torn_rope() {
+        let mut rope = Rope {
+            knots: vec![(0, 0), (4, 0)],
+        };
+        assert_eq!(
+            rope.move_direction(&Direction::Up),
+            Err(Error::Unsolvable(
+                "the knot at (4, 0) can not follow the knot at (0, 1)".to_string()
+            ))
+        );
     }
 }
@@ -1,9 +1,18 @@
+use aoc_core::{Answer, Error, Solution};
+use aoc_parse::{lines, parse_all, signed, tag, IResult};
 use itertools::Itertools;
+use nom::{
+    branch::alt,
+    character::complete::alpha1,
+    combinator::{map, value},
+    error::context,
+    sequence::{preceded, separated_pair},
+};
 use std::collections::HashMap;
 
 type Register = HashMap<String, i32>;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum Command {
     Add((String, i32)),
     Noop,
@@ -16,31 +25,32 @@ impl Command {
             Command::Noop => 1,
         }
     }
-
-    fn get_max_cycle(&self) -> u32 {
-        2
-    }
 }
 
-pub fn get_commands(file: String) -> Vec<Command> {
-    file.lines()
-        .map(|line| {
-            if line.contains("add") {
-                let line = line.replace("add", "");
-                let (register_name, register_value) = line.split_once(' ').unwrap();
-                let register_value = register_value.parse::<i32>().unwrap();
-                return Command::Add((register_name.to_owned(), register_value));
-            }
+/// `addx 3` or `noop`
+fn parse_command(input: &str) -> IResult<'_, Command> {
+    context(
+        "`addx V` or `noop`",
+        alt((
+            map(
+                preceded(tag("add"), separated_pair(alpha1, tag(" "), signed)),
+                |(register_name, register_value): (&str, i32)| {
+                    Command::Add((register_name.to_owned(), register_value))
+                },
+            ),
+            value(Command::Noop, tag("noop")),
+        )),
+    )(input)
+}
 
-            Command::Noop
-        })
-        .collect()
+pub fn get_commands(file: String) -> Result<Vec<Command>, Error> {
+    parse_all(&file, lines(parse_command))
 }
 
-pub fn process_input1(file: String) -> i32 {
-    let sample_cycles = vec![20, 60, 100, 140, 180, 220];
+pub fn process_input1(file: String) -> Result<i32, Error> {
+    let sample_cycles = [20, 60, 100, 140, 180, 220];
 
-    let commands = get_commands(file);
+    let commands = get_commands(file)?;
     let mut register = Register::new();
     let mut current_cycle = 0;
     let mut signal_strenth = 0;
@@ -69,11 +79,11 @@ pub fn process_input1(file: String) -> i32 {
         };
     }
 
-    signal_strenth
+    Ok(signal_strenth)
 }
 
-pub fn process_input2(file: String) -> String {
-    let commands = get_commands(file);
+pub fn process_input2(file: String) -> Result<String, Error> {
+    let commands = get_commands(file)?;
     let mut register = Register::new();
     let mut current_cycle = 0;
     let mut crt = String::from("");
@@ -103,28 +113,73 @@ pub fn process_input2(file: String) -> String {
             Command::Noop => {}
         };
     }
-    crt.chars()
+    Ok(crt
+        .chars()
         .chunks(40)
         .into_iter()
         .map(|chunk| chunk.collect::<String>())
-        .join("\n")
+        .join("\n"))
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = i32;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = Answer;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string()).map(Answer::grid)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::{assert_snapshot, tasks, tasks::assert_examples};
+
+    const TASKS: &str = include_str!("../../../tasks.md");
 
     #[test]
     fn part_1() {
-        let file = include_str!("test.txt");
-        let output = concat!(
-            "##..##..##..##..##..##..##..##..##..##..\n",
-            "###...###...###...###...###...###...###.\n",
-            "####....####....####....####....####....\n",
-            "#####.....#####.....#####.....#####.....\n",
-            "######......######......######......####\n",
-            "#######.......#######.......#######....."
+        assert_examples::<Part1>(TASKS);
+    }
+
+    #[test]
+    fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+
+    #[test]
+    fn crt_snapshot() {
+        let example = &tasks::examples(TASKS, 10, 2)[0];
+        assert_snapshot!("crt", process_input2(example.input.clone()).unwrap());
+    }
+
+    #[test]
+    fn unknown_command() {
+        let error = get_commands("noop\naddx 3\njump 2\n".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 1: expected `addx V` or `noop`"
         );
-        assert_eq!(process_input2(file.to_string()), output);
     }
 }
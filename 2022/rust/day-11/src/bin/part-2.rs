@@ -1,10 +1,12 @@
-use day_11::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_11::{process_input2, Part2};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 01: after 10000 rounds of stuff-slinging simian shenanigans, the monkey business value is {:?}",
-        process_input2(file, 10_000)
+        process_input2(file, 10_000)?
     );
+
+    Ok(())
 }
@@ -0,0 +1,140 @@
+use aoc_core::generate::Rng;
+
+/// the divisors of the tests, distinct primes keep part 2 solvable with their product
+const PRIMES: [u64; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+struct Monkey {
+    items: Vec<u64>,
+    /// `*` or `+`, and `None` for `old`
+    operation: (char, Option<u64>),
+    divisible: u64,
+    targets: [usize; 2],
+}
+
+/// three to eight monkeys holding `size` items. One of them squares the worry level, they never
+/// throw to themselves.
+fn monkeys(rng: &mut Rng, size: usize) -> Vec<Monkey> {
+    let count = rng.range(3..=8) as usize;
+    let mut divisors = PRIMES;
+    rng.shuffle(&mut divisors);
+    let squaring = rng.below(count);
+
+    let mut monkeys: Vec<Monkey> = (0..count)
+        .map(|index| {
+            let first = (index + 1 + rng.below(count - 1)) % count;
+            let mut second = (index + 1 + rng.below(count - 1)) % count;
+            if second == first {
+                second = (0..count)
+                    .find(|&other| other != index && other != first)
+                    .unwrap();
+            }
+            let operation = if index == squaring {
+                ('*', None)
+            } else if rng.chance(50) {
+                ('*', Some(rng.range(2..=19) as u64))
+            } else {
+                ('+', Some(rng.range(1..=9) as u64))
+            };
+
+            Monkey {
+                items: vec![],
+                operation,
+                divisible: divisors[index],
+                targets: [first, second],
+            }
+        })
+        .collect();
+
+    // every monkey starts with at least one item
+    for index in 0..size.max(count) {
+        let owner = if index < count {
+            index
+        } else {
+            rng.below(count)
+        };
+        monkeys[owner].items.push(rng.range(40..=99) as u64);
+    }
+    monkeys
+}
+
+fn describe(monkeys: &[Monkey]) -> String {
+    monkeys
+        .iter()
+        .enumerate()
+        .map(|(index, monkey)| {
+            let items: Vec<String> = monkey.items.iter().map(u64::to_string).collect();
+            let value = monkey
+                .operation
+                .1
+                .map_or("old".to_string(), |value| value.to_string());
+            format!(
+                "Monkey {index}:\n  Starting items: {}\n  Operation: new = old {} {value}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {}\n    \
+                 If false: throw to monkey {}\n",
+                items.join(", "),
+                monkey.operation.0,
+                monkey.divisible,
+                monkey.targets[0],
+                monkey.targets[1]
+            )
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// the notes of three to eight monkeys, which hold `size` items between them
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    describe(&monkeys(rng, size))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    /// the monkey business after `rounds` without relief, with the worry levels kept small by the
+    /// product of the divisors
+    fn monkey_business(mut monkeys: Vec<Monkey>, rounds: usize) -> usize {
+        let modulus: u64 = monkeys.iter().map(|monkey| monkey.divisible).product();
+        let mut inspections = vec![0; monkeys.len()];
+
+        for _ in 0..rounds {
+            for index in 0..monkeys.len() {
+                for item in std::mem::take(&mut monkeys[index].items) {
+                    let monkey = &monkeys[index];
+                    let value = monkey.operation.1.unwrap_or(item);
+                    let item = match monkey.operation.0 {
+                        '*' => item * value,
+                        _ => item + value,
+                    } % modulus;
+                    let target =
+                        monkey.targets[usize::from(!item.is_multiple_of(monkey.divisible))];
+
+                    inspections[index] += 1;
+                    monkeys[target].items.push(item);
+                }
+            }
+        }
+
+        inspections.sort_unstable();
+        inspections.iter().rev().take(2).product()
+    }
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..20 {
+            let monkeys = monkeys(&mut Rng::new(seed), 30);
+            let input = describe(&monkeys);
+
+            assert!(
+                process_input1(input.clone(), 20).unwrap() > 0,
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input, 1_000),
+                Ok(monkey_business(monkeys, 1_000)),
+                "seed {seed}"
+            );
+        }
+    }
+}
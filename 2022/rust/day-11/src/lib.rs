@@ -1,9 +1,20 @@
-use std::{collections::VecDeque, str::FromStr};
+mod generate;
+use aoc_core::{Error, Solution};
+use aoc_parse::{blocks, field, list, parse_all, tag, unsigned, IResult};
+use nom::{
+    branch::alt,
+    character::complete::line_ending,
+    combinator::{map, value},
+    error::context,
+    sequence::{preceded, terminated, tuple},
+};
+use std::collections::VecDeque;
+
+pub use generate::generate;
 
 type WorryLevel = u64;
-type MonkeyIndex = u32;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum Value {
     Old,
     Num(u64),
@@ -32,7 +43,7 @@ pub struct Monkey {
 
 impl Monkey {
     fn test(&mut self, worry_level: WorryLevel) -> usize {
-        if worry_level % self.test.divisible == 0_u64 {
+        if worry_level.is_multiple_of(self.test.divisible) {
             return self.test.monkey_true;
         }
 
@@ -87,74 +98,61 @@ impl Monkey {
     }
 }
 
-pub fn parse_monkeys(file: String) -> Vec<Monkey> {
-    file.split("\n\n")
-        .map(|monkey_chunk| {
-            let mut monkey_lines = monkey_chunk.trim().split('\n').skip(1);
-
-            let starting_items: VecDeque<u64> = monkey_lines
-                .next()
-                .unwrap()
-                .split_once(':')
-                .unwrap()
-                .1
-                .split(',')
-                .map(|item| FromStr::from_str(item.trim()).unwrap())
-                .collect();
-
-            let operation = monkey_lines
-                .next()
-                .unwrap()
-                .split_once('=')
-                .unwrap()
-                .1
-                .replacen("old", "", 1);
-            let operation: Vec<&str> = operation.trim().split(' ').collect();
-
-            let operation = match operation[0] {
-                "*" => {
-                    if operation[1] != "old" {
-                        let value: u64 = FromStr::from_str(operation[1].trim()).unwrap();
-                        Operation::Mult(Value::Num(value))
-                    } else {
-                        Operation::Mult(Value::Old)
-                    }
-                }
-                _ => {
-                    if operation[1] != "old" {
-                        let value: u64 = FromStr::from_str(operation[1].trim()).unwrap();
-                        Operation::Add(Value::Num(value))
-                    } else {
-                        Operation::Add(Value::Old)
-                    }
-                }
-            };
-
-            let divisible = monkey_lines.next().unwrap().split_once("by").unwrap().1;
-            let divisible: u64 = FromStr::from_str(divisible.trim()).unwrap();
-
-            let monkey_true = monkey_lines.next().unwrap().split_once("monkey").unwrap().1;
-            let monkey_true: usize = FromStr::from_str(monkey_true.trim()).unwrap();
-
-            let monkey_false = monkey_lines.next().unwrap().split_once("monkey").unwrap().1;
-            let monkey_false: usize = FromStr::from_str(monkey_false.trim()).unwrap();
-
-            Monkey {
-                items: starting_items,
-                operation,
-                test: Test {
-                    divisible,
-                    monkey_true,
-                    monkey_false,
-                },
-                inspections: 0,
-            }
-        })
-        .collect()
+fn parse_value(input: &str) -> IResult<'_, Value> {
+    alt((value(Value::Old, tag("old")), map(unsigned, Value::Num)))(input)
 }
 
-pub fn process_input1(file: String, simulate_rounds: usize) -> usize {
-    let mut monkeys = parse_monkeys(file);
+/// `new = old * 19` or `new = old + old`
+fn parse_operation(input: &str) -> IResult<'_, Operation> {
+    preceded(
+        tag("new = old "),
+        context(
+            "`* {value}` or `+ {value}`",
+            alt((
+                map(preceded(tag("* "), parse_value), Operation::Mult),
+                map(preceded(tag("+ "), parse_value), Operation::Add),
+            )),
+        ),
+    )(input)
+}
+
+fn parse_monkey(input: &str) -> IResult<'_, Monkey> {
+    let (input, _) = tuple((tag("Monkey "), unsigned::<usize>, tag(":"), line_ending))(input)?;
+    let (input, items) =
+        terminated(field("Starting items", list(",", unsigned)), line_ending)(input)?;
+    let (input, operation) = terminated(field("Operation", parse_operation), line_ending)(input)?;
+    let (input, divisible) = terminated(
+        field("Test", preceded(tag("divisible by "), unsigned)),
+        line_ending,
+    )(input)?;
+    let (input, monkey_true) = terminated(
+        field("If true", preceded(tag("throw to monkey "), unsigned)),
+        line_ending,
+    )(input)?;
+    let (input, monkey_false) =
+        field("If false", preceded(tag("throw to monkey "), unsigned))(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            items: items.into(),
+            operation,
+            test: Test {
+                divisible,
+                monkey_true,
+                monkey_false,
+            },
+            inspections: 0,
+        },
+    ))
+}
+
+pub fn parse_monkeys(file: String) -> Result<Vec<Monkey>, Error> {
+    parse_all(&file, blocks(parse_monkey))
+}
+
+pub fn process_input1(file: String, simulate_rounds: usize) -> Result<usize, Error> {
+    let mut monkeys = parse_monkeys(file)?;
 
     // in each round, all monkeys inspect all items they hold
     // they test it and throw it to the target monkey
@@ -175,16 +173,16 @@ pub fn process_input1(file: String, simulate_rounds: usize) -> usize {
 
     // get the monkey business of the top 2 monkeys
     monkeys.sort_by_key(|monkey| monkey.inspections);
-    monkeys
+    Ok(monkeys
         .iter()
         .rev()
         .take(2)
         .map(|monkey| monkey.inspections)
-        .product()
+        .product())
 }
 
-pub fn process_input2(file: String, simulate_rounds: usize) -> usize {
-    let mut monkeys = parse_monkeys(file);
+pub fn process_input2(file: String, simulate_rounds: usize) -> Result<usize, Error> {
+    let mut monkeys = parse_monkeys(file)?;
 
     // in each round, all monkeys inspect all items they hold
     // they test it and throw it to the target monkey
@@ -207,12 +205,64 @@ pub fn process_input2(file: String, simulate_rounds: usize) -> usize {
     // get the monkey business of the top 2 monkeys
     monkeys.sort_by_key(|monkey| monkey.inspections);
 
-    monkeys
+    Ok(monkeys
         .iter()
         .rev()
         .take(2)
         .map(|monkey| monkey.inspections)
-        .product()
+        .product())
+}
+
+/// the amount of rounds the monkeys play in part 1
+pub struct Part1Params {
+    pub rounds: usize,
+}
+
+impl Default for Part1Params {
+    fn default() -> Self {
+        Self { rounds: 20 }
+    }
+}
+
+/// the amount of rounds the monkeys play in part 2
+pub struct Part2Params {
+    pub rounds: usize,
+}
+
+impl Default for Part2Params {
+    fn default() -> Self {
+        Self { rounds: 10_000 }
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const PART: u8 = 1;
+
+    type Params = Part1Params;
+    type Answer = usize;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string(), params.rounds)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+    const PART: u8 = 2;
+
+    type Params = Part2Params;
+    type Answer = usize;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string(), params.rounds)
+    }
 }
 
 #[cfg(test)]
@@ -220,14 +270,26 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part1() {
+    fn part1() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string(), 20), 10605);
+        assert_eq!(process_input1(file.to_string(), 20)?, 10605);
+        Ok(())
     }
 
     #[test]
-    fn part2() {
+    fn part2() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input2(file.to_string(), 10_000), 2713310158);
+        assert_eq!(process_input2(file.to_string(), 10_000)?, 2713310158);
+        Ok(())
+    }
+
+    #[test]
+    fn malformed_monkey() {
+        let file = include_str!("test.txt").replace("If false: throw to monkey 1", "If false: 1");
+        let error = parse_monkeys(file).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 27, column 15: expected `throw to monkey `"
+        );
     }
 }
@@ -1,10 +1,12 @@
-use day_12::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_12::{process_input2, Part2};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 01: the shortes path starting from any 'S' or 'a' elevation is {:?} long",
-        process_input2(file)
+        process_input2(file)?
     );
+
+    Ok(())
 }
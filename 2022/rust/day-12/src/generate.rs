@@ -0,0 +1,107 @@
+use aoc_core::generate::Rng;
+
+/// A heightmap `size` wide (at least 26) with `S` in the first and `E` in the last column. A
+/// path between them climbs from `a` to `z` one step at a time, the other squares are random
+/// heights from `a` to `y`, so `E` is the only `z`.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let width = size.max(26);
+    let height = (width / 2).max(5);
+    let mut map: Vec<Vec<u8>> = (0..height)
+        .map(|_| (0..width).map(|_| b'a' + rng.below(25) as u8).collect())
+        .collect();
+
+    // walk to the right and up or down on the way to the row of the end
+    let start_row = rng.below(height);
+    let end_row = rng.below(height);
+    let mut path = vec![(0, start_row)];
+    let (mut x, mut y) = (0, start_row);
+    while (x, y) != (width - 1, end_row) {
+        let vertical = y != end_row && (x == width - 1 || rng.chance(30));
+        if vertical {
+            y = if end_row > y { y + 1 } else { y - 1 };
+        } else {
+            x += 1;
+        }
+        path.push((x, y));
+    }
+
+    let steps = path.len() - 1;
+    for (step, &(x, y)) in path.iter().enumerate() {
+        map[y][x] = b'a' + (step * 25 / steps) as u8;
+    }
+    map[start_row][0] = b'S';
+    map[end_row][width - 1] = b'E';
+
+    map.into_iter()
+        .map(|row| String::from_utf8(row).expect("the heights are letters") + "\n")
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::VecDeque;
+
+    use super::*;
+    use crate::{process_input1, process_input2};
+
+    /// the fewest steps from any of the squares in `starts` to `E`
+    fn fewest_steps(input: &str, starts: &[u8]) -> i32 {
+        let map: Vec<&[u8]> = input.lines().map(str::as_bytes).collect();
+        let height = |char: u8| match char {
+            b'S' => b'a',
+            b'E' => b'z',
+            char => char,
+        };
+
+        let mut steps = vec![vec![None; map[0].len()]; map.len()];
+        let mut queue = VecDeque::new();
+        for (y, row) in map.iter().enumerate() {
+            for (x, char) in row.iter().enumerate() {
+                if starts.contains(char) {
+                    steps[y][x] = Some(0);
+                    queue.push_back((x, y));
+                }
+            }
+        }
+
+        while let Some((x, y)) = queue.pop_front() {
+            let here = steps[y][x].unwrap();
+            if map[y][x] == b'E' {
+                return here;
+            }
+            let neighbours = [
+                (x + 1, y),
+                (x.wrapping_sub(1), y),
+                (x, y + 1),
+                (x, y.wrapping_sub(1)),
+            ];
+            for (nx, ny) in neighbours {
+                let Some(&char) = map.get(ny).and_then(|row| row.get(nx)) else {
+                    continue;
+                };
+                if height(char) <= height(map[y][x]) + 1 && steps[ny][nx].is_none() {
+                    steps[ny][nx] = Some(here + 1);
+                    queue.push_back((nx, ny));
+                }
+            }
+        }
+        panic!("there is no path to E");
+    }
+
+    #[test]
+    fn solves_generated_inputs() {
+        for seed in 0..20 {
+            let input = generate(&mut Rng::new(seed), 30);
+            assert_eq!(
+                process_input1(input.clone()),
+                Ok(fewest_steps(&input, b"S")),
+                "seed {seed}"
+            );
+            assert_eq!(
+                process_input2(input.clone()),
+                Ok(fewest_steps(&input, b"Sa")),
+                "seed {seed}"
+            );
+        }
+    }
+}
@@ -1,15 +1,14 @@
-use std::io::Write;
-
+mod generate;
+use aoc_core::{prepare::Shape, Error, Solution};
+use aoc_grid::{Coord, Grid};
 use itertools::Itertools;
-use petgraph::{
-    algo::{astar, dijkstra},
-    dot::Dot,
-    prelude::NodeIndex,
-    Graph,
-};
+use petgraph::{algo::dijkstra, prelude::NodeIndex, Graph};
+
+pub use generate::generate;
 
 type Height = i32;
-type Position = (isize, isize, char, Height);
+/// (coord, char, height) of a square of the heightmap
+type Position = (Coord, char, Height);
 
 /// Parse char to height. a == 1, ... z == 26. S equals a and E equals z.
 fn parse_height(char: char) -> Height {
@@ -20,45 +19,29 @@ fn parse_height(char: char) -> Height {
     } as i32)
 }
 
-fn parse_graph(file: String) -> Graph<Position, Height> {
-    let mut graph: Graph<Position, Height> = Graph::new();
-    let grid: Vec<Vec<char>> = file.lines().map(|line| line.chars().collect()).collect();
+fn parse_graph(file: &str) -> Result<Graph<Position, Height>, Error> {
+    let grid = Grid::parse(file, "a height from `a` to `z`, `S` or `E`", |char| {
+        matches!(char, 'a'..='z' | 'S' | 'E').then_some(char)
+    })?;
 
     // fill the graph with position nodes from the grid
-    for y in 0..grid.len() {
-        for x in 0..grid[y].len() {
-            // current position
-            let node_height = parse_height(grid[y][x]);
-            let position_coord = (x as isize, y as isize, grid[y][x], node_height);
-            graph.add_node(position_coord);
-        }
-    }
+    let mut graph: Graph<Position, Height> = Graph::new();
+    let nodes = grid.map(|coord, char| graph.add_node((coord, *char, parse_height(*char))));
 
-    // create edges between cross_positions of nodes (UP, RIGHT, DOWN, LEFT)
-    for node_index in graph.node_indices() {
-        let node = graph[node_index];
+    // create edges between the cross positions of nodes (UP, RIGHT, DOWN, LEFT)
+    for (coord, node_index) in nodes.iter() {
+        for cross_coord in grid.neighbours4(coord) {
+            let cross_index = nodes[cross_coord];
+            let height_difference = graph[cross_index].2 - graph[*node_index].2;
 
-        // get all cross_position nodes of the current node_index. Connect this node to its cross
-        // node via add_edge.
-        for cross_index in graph.node_indices() {
-            let cross_node = graph[cross_index];
-            let is_cross_node = cross_node.0 == node.0 - 1 && cross_node.1 == node.1 || // [x - 1][y]
-                cross_node.0 == node.0 + 1 && cross_node.1 == node.1 || // [x + 1][y]
-                cross_node.0 == node.0 && cross_node.1 == node.1 - 1 || // [x][y - 1]
-                cross_node.0 == node.0 && cross_node.1 == node.1 + 1;
-
-            if is_cross_node {
-                let height_difference = cross_node.3 - node.3;
-
-                // only <1 height difference is walkable
-                if height_difference <= 1 {
-                    graph.add_edge(node_index, cross_index, 1);
-                }
+            // only <1 height difference is walkable
+            if height_difference <= 1 {
+                graph.add_edge(*node_index, cross_index, 1);
             }
         }
     }
 
-    graph
+    Ok(graph)
 }
 
 /// gets the length of the path from start_index to goal_index. If the goal_index is not connected
@@ -69,6 +52,9 @@ fn get_path_len_largest_height_smallest_path(
     goal_index: NodeIndex,
 ) -> (Position, i32) {
     let dijkstra_path = dijkstra(graph, start_index, Some(goal_index), |edge| *edge.weight());
+    if let Some(path_len) = dijkstra_path.get(&goal_index) {
+        return (graph[goal_index], *path_len);
+    }
 
     // get the largest height node, with the smalles path length
     let mut end_node: Option<Position> = None;
@@ -79,8 +65,8 @@ fn get_path_len_largest_height_smallest_path(
 
         match end_node {
             Some(s_end_node) => {
-                let node_height_equal = node.3 == s_end_node.3;
-                let node_height_taller = node.3 > s_end_node.3;
+                let node_height_equal = node.2 == s_end_node.2;
+                let node_height_taller = node.2 > s_end_node.2;
                 let node_path_smaller = path_len < end_node_path_len;
 
                 if (node_height_equal || node_height_taller && node_path_smaller)
@@ -104,40 +90,82 @@ fn get_path_len_largest_height_smallest_path(
     (end_node.unwrap(), end_node_path_len)
 }
 
-pub fn process_input1(file: String) -> i32 {
-    let graph = parse_graph(file);
+/// the node of the square marked with `char`
+fn find_square(graph: &Graph<Position, Height>, char: char) -> Result<NodeIndex, Error> {
+    graph
+        .node_indices()
+        .find(|i| graph[*i].1 == char)
+        .ok_or_else(|| Error::Unsolvable(format!("the heightmap has no `{char}` square")))
+}
+
+pub fn process_input1(file: String) -> Result<i32, Error> {
+    let graph = parse_graph(&file)?;
 
-    let start_index = graph.node_indices().find(|i| graph[*i].2 == 'S').unwrap();
-    let goal_index = graph.node_indices().find(|i| graph[*i].2 == 'E').unwrap();
+    let start_index = find_square(&graph, 'S')?;
+    let goal_index = find_square(&graph, 'E')?;
 
-    get_path_len_largest_height_smallest_path(&graph, start_index, goal_index).1
+    Ok(get_path_len_largest_height_smallest_path(&graph, start_index, goal_index).1)
 }
 
-pub fn process_input2(file: String) -> i32 {
-    let graph = parse_graph(file);
+pub fn process_input2(file: String) -> Result<i32, Error> {
+    let graph = parse_graph(&file)?;
 
-    let goal_index = graph.node_indices().find(|i| graph[*i].2 == 'E').unwrap();
+    let goal_index = find_square(&graph, 'E')?;
     let start_indicies_tuple = graph
         .node_indices()
-        .filter(|i| graph[*i].2 == 'S' || graph[*i].2 == 'a')
+        .filter(|i| graph[*i].1 == 'S' || graph[*i].1 == 'a')
         .map(|start_index| {
             get_path_len_largest_height_smallest_path(&graph, start_index, goal_index)
         });
 
     let largest_heights = start_indicies_tuple
-        .sorted_by_key(|node| node.0 .3)
+        .sorted_by_key(|node| node.0 .2)
         .collect_vec();
 
-    let largest_height = *largest_heights.last().unwrap();
+    let largest_height = *largest_heights
+        .last()
+        .ok_or_else(|| Error::Unsolvable("the heightmap has no `S` or `a` square".to_string()))?;
     let smallest_len_at_largest_height = largest_heights
         .iter()
-        .filter(|position| position.0 .3 == largest_height.0 .3)
+        .filter(|position| position.0 .2 == largest_height.0 .2)
         .sorted_by_key(|position| position.1)
         .rev()
-        .last()
+        .next_back()
         .unwrap(); // sort by length
 
-    smallest_len_at_largest_height.1
+    Ok(smallest_len_at_largest_height.1)
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = i32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = i32;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
@@ -145,14 +173,41 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part1() {
+    fn part1() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string()), 31);
+        assert_eq!(process_input1(file.to_string())?, 31);
+        Ok(())
     }
 
     #[test]
-    fn part2() {
+    fn part2() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input2(file.to_string()), 29);
+        assert_eq!(process_input2(file.to_string())?, 29);
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_square() {
+        let error = process_input1("Sab\nab1\nyzE".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 2, column 3: expected a height from `a` to `z`, `S` or `E`"
+        );
+    }
+
+    #[test]
+    fn missing_squares() {
+        assert_eq!(
+            process_input1("Sab\nabc\n".to_string()),
+            Err(Error::Unsolvable(
+                "the heightmap has no `E` square".to_string()
+            ))
+        );
+        assert_eq!(
+            process_input2("bcd\nyzE\n".to_string()),
+            Err(Error::Unsolvable(
+                "the heightmap has no `S` or `a` square".to_string()
+            ))
+        );
     }
 }
@@ -1,10 +1,12 @@
-use day_13::process_input1;
-use std::fs;
+use aoc_core::{input, Error};
+use day_13::{process_input1, Part1};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 01: sum of indicies+1 where pairs are in the right order is {:?}",
-        process_input1(file)
+        process_input1(file)?
     );
+
+    Ok(())
 }
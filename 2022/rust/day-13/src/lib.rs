@@ -1,6 +1,12 @@
+use aoc_core::{Error, Solution};
+use aoc_parse::{blocks, parse_all, tag, unsigned, IResult};
 use nom::{
-    branch::alt, bytes::complete::tag, character::complete::u32, combinator::map,
-    multi::separated_list0, sequence::delimited, IResult,
+    branch::alt,
+    character::complete::line_ending,
+    combinator::map,
+    error::context,
+    multi::separated_list0,
+    sequence::{delimited, separated_pair},
 };
 
 use std::cmp::Ordering;
@@ -41,12 +47,12 @@ pub struct PacketPair {
 }
 
 impl PacketPair {
-    fn parse_integer(single: &str) -> IResult<&str, Packet> {
-        map(u32, Packet::Integer)(single)
+    fn parse_integer(single: &str) -> IResult<'_, Packet> {
+        map(unsigned, Packet::Integer)(single)
     }
 
     /// single == "[1, 3, 4, [1, 2, 3]]"
-    fn parse_list(single: &str) -> IResult<&str, Packet> {
+    fn parse_list(single: &str) -> IResult<'_, Packet> {
         let list_parser = delimited(
             tag("["),
             separated_list0(tag(","), Self::parse_packets),
@@ -56,44 +62,45 @@ impl PacketPair {
     }
 
     /// parses either "8" or "[8]" to Packet
-    fn parse_packets(single: &str) -> IResult<&str, Packet> {
-        alt((Self::parse_integer, Self::parse_list))(single)
+    fn parse_packets(single: &str) -> IResult<'_, Packet> {
+        context(
+            "a number or a list like `[1,[2]]`",
+            alt((Self::parse_integer, Self::parse_list)),
+        )(single)
     }
 
-    fn from_str(pair: &str) -> Self {
-        let (left, right) = pair.split_once('\n').unwrap();
-
-        Self {
-            left: Self::parse_packets(left).unwrap().1,
-            right: Self::parse_packets(right).unwrap().1,
-        }
+    /// two packets on lines of their own
+    fn parse_pair(pair: &str) -> IResult<'_, Self> {
+        let (pair, (left, right)) =
+            separated_pair(Self::parse_packets, line_ending, Self::parse_packets)(pair)?;
+        Ok((pair, Self { left, right }))
     }
 }
 
-pub fn parse_packet_pairs(file: String) -> Vec<PacketPair> {
-    file.split("\n\n").map(PacketPair::from_str).collect()
+pub fn parse_packet_pairs(file: String) -> Result<Vec<PacketPair>, Error> {
+    parse_all(&file, blocks(PacketPair::parse_pair))
 }
 
-pub fn parse_packets(file: String) -> Vec<Packet> {
-    file.lines()
-        .filter(|line| !line.is_empty())
-        .map(|line| PacketPair::parse_packets(line).unwrap().1)
-        .collect()
+pub fn parse_packets(file: String) -> Result<Vec<Packet>, Error> {
+    Ok(parse_packet_pairs(file)?
+        .into_iter()
+        .flat_map(|pair| [pair.left, pair.right])
+        .collect())
 }
 
-pub fn process_input1(file: String) -> usize {
-    let pairs = parse_packet_pairs(file);
+pub fn process_input1(file: String) -> Result<usize, Error> {
+    let pairs = parse_packet_pairs(file)?;
 
-    pairs
+    Ok(pairs
         .iter()
         .enumerate()
         .filter(|(_, pair)| pair.left <= pair.right)
         .map(|(index, _)| index + 1)
-        .sum()
+        .sum())
 }
 
-pub fn process_input2(file: String) -> usize {
-    let mut packets: Vec<_> = parse_packets(file);
+pub fn process_input2(file: String) -> Result<usize, Error> {
+    let mut packets: Vec<_> = parse_packets(file)?;
 
     let divider_2 = Packet::List(vec![Packet::List(vec![Packet::Integer(2)])]);
     let divider_6 = Packet::List(vec![Packet::List(vec![Packet::Integer(6)])]);
@@ -103,27 +110,78 @@ pub fn process_input2(file: String) -> usize {
 
     packets.sort();
 
-    packets
+    Ok(packets
         .iter()
         .enumerate()
         .filter(|(_, packet)| **packet == divider_6 || **packet == divider_2)
         .map(|(index, _)| index + 1)
-        .product()
+        .product())
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::DynSolution;
 
     #[test]
-    fn part1() {
+    fn part1() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string()), 13);
+        assert_eq!(process_input1(file.to_string())?, 13);
+        Ok(())
     }
 
     #[test]
-    fn part2() {
+    fn part2() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input2(file.to_string()), 140);
+        assert_eq!(process_input2(file.to_string())?, 140);
+        Ok(())
+    }
+
+    #[test]
+    fn prepared_windows_input() {
+        let file = include_str!("test.txt").replace('\n', " \r\n");
+        assert_eq!(Part1.run(&file).unwrap().to_string(), "13");
+    }
+
+    #[test]
+    fn malformed_packets() {
+        let error = process_input1("[1,[2]]\n[3]\n\n[1]\n[x]\n".to_string()).unwrap_err();
+        assert_eq!(error.to_string(), "line 5, column 2: expected `]`");
+
+        let error = process_input2("[1]\n[2]\n\n[3]\n".to_string()).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "line 5, column 1: expected a number or a list like `[1,[2]]`"
+        );
     }
 }
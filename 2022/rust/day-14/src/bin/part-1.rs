@@ -1,10 +1,12 @@
-use day_14::process_input1;
-use std::fs;
+use aoc_core::{input, Error};
+use day_14::{process_input1, Part1};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 01: sum of sand particles come to rest is {:?}",
         process_input1(file)
     );
+
+    Ok(())
 }
@@ -1,10 +1,12 @@
-use day_14::process_input2;
-use std::fs;
+use aoc_core::{input, Error};
+use day_14::{process_input2, Part2};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 02: sum of sand particles come to rest is {:?}",
         process_input2(file)
     );
+
+    Ok(())
 }
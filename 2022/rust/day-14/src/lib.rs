@@ -1,6 +1,8 @@
+use aoc_core::{debug, trace, Error, Solution};
+use aoc_grid::{Coord, Direction, Grid};
 use itertools::{self, Itertools};
 use std::collections::HashSet;
-use std::fmt::{Display, Formatter, Result};
+use std::fmt::{self, Display, Formatter};
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 enum GridTile {
@@ -21,7 +23,7 @@ impl GridTile {
     }
 }
 impl Display for GridTile {
-    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             GridTile::Rock => write!(f, "#"),
             GridTile::Sand => write!(f, "o"),
@@ -30,116 +32,71 @@ impl Display for GridTile {
         }
     }
 }
-struct Grid {
-    grid: Vec<Vec<GridTile>>,
-    width: usize,
-    height: usize,
+/// the cave around the sand source. The cave coordinates are shifted into the grid, so that
+/// (`max_x`, `max_y`) is its bottom right corner.
+struct Cave {
+    grid: Grid<GridTile>,
     max_x: usize,
     max_y: usize,
 }
 
-impl Grid {
+/// the grid with the number of each row in front of it
+impl Display for Cave {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y_index, tiles) in self.grid.rows().enumerate() {
+            write!(f, "{y_index:03} | ")?;
+            for tile in tiles {
+                write!(f, "{tile}")?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Cave {
     fn new(width: usize, height: usize, max_x: usize, max_y: usize) -> Self {
-        Grid {
-            grid: vec![vec![GridTile::Air; width]; height],
-            width,
-            height,
+        Cave {
+            grid: Grid::new(width, height, GridTile::Air),
             max_x,
             max_y,
         }
     }
 
     fn get_relative_x(&self, x: usize) -> usize {
-        self.width - ((self.max_x - x) + 1)
+        self.grid.width() - ((self.max_x - x) + 1)
     }
     fn get_relative_y(&self, y: usize) -> usize {
-        self.height - ((self.max_y - y) + 1)
+        self.grid.height() - ((self.max_y - y) + 1)
     }
 
-    /// display the grid in stdout
-    fn display(&self) {
-        // print!("\x1B[2J");
-        for (y_index, tiles) in self.grid.iter().enumerate() {
-            let three_digit_index = if y_index < 10 {
-                "00".to_string() + y_index.to_string().as_str()
-            } else if y_index < 100 {
-                "0".to_string() + y_index.to_string().as_str()
-            } else {
-                y_index.to_string()
-            };
-            print!("{three_digit_index} | ");
-            for tile in tiles {
-                print!("{tile}");
-            }
-            println!()
-        }
-    }
-
-    fn spawn_sand(&mut self, position: (usize, usize)) -> Option<(usize, usize)> {
-        let tile = self.get_tile(position);
-        if tile.is_some() {
-            // self.grid[position.1][position.0] = GridTile::Sand;
-            return Some((position.0, position.1));
-        }
-        None
-    }
-
-    fn get_tile(&self, position: (usize, usize)) -> Option<&GridTile> {
-        if let Some(row) = self.grid.get(position.1) {
-            row.get(position.0)
-        } else {
-            None
-        }
+    fn spawn_sand(&mut self, position: Coord) -> Option<Coord> {
+        self.grid.get(position).map(|_| position)
     }
 
     /// moves sand either down, left or right if the tile there is movable. Then returns if the
     /// sand has moved
-    fn move_sand(&mut self, sand_pos: (usize, usize)) -> Option<(usize, usize)> {
+    fn move_sand(&mut self, sand_pos: Coord) -> Option<Coord> {
         // position check to keep sand in bounds of grid
-        if sand_pos.1 == self.grid.len() - 1
-            || sand_pos.0 == self.grid[0].len() - 1
-            || sand_pos.0 == 0
-            || sand_pos.1 == 0
+        if sand_pos.y == self.grid.height() - 1
+            || sand_pos.x == self.grid.width() - 1
+            || sand_pos.x == 0
+            || sand_pos.y == 0
         {
             return None;
         }
 
-        let down = (sand_pos.0, sand_pos.1 + 1);
-        let left = (sand_pos.0 - 1, sand_pos.1 + 1);
-        let right = (sand_pos.0 + 1, sand_pos.1 + 1);
-
-        let current_tile = self.get_tile(sand_pos);
-        let down_tile = self.get_tile(down);
-        let left_tile = self.get_tile(left);
-        let right_tile = self.get_tile(right);
-
-        if let Some(down_tile) = down_tile {
-            if down_tile.movable() {
-                if *current_tile.unwrap() != GridTile::SandSource {
-                    self.grid[sand_pos.1][sand_pos.0] = GridTile::Air;
-                }
-                self.grid[down.1][down.0] = GridTile::Sand;
-                return Some((down.0, down.1));
-            }
-        }
-
-        if let Some(left_tile) = left_tile {
-            if left_tile.movable() {
-                if *current_tile.unwrap() != GridTile::SandSource {
-                    self.grid[sand_pos.1][sand_pos.0] = GridTile::Air;
-                }
-                self.grid[left.1][left.0] = GridTile::Sand;
-                return Some((left.0, left.1));
-            }
-        }
+        for direction in [Direction::Down, Direction::DownLeft, Direction::DownRight] {
+            let Some(next) = self.grid.step(sand_pos, direction) else {
+                continue;
+            };
 
-        if let Some(right_tile) = right_tile {
-            if right_tile.movable() {
-                if *current_tile.unwrap() != GridTile::SandSource {
-                    self.grid[sand_pos.1][sand_pos.0] = GridTile::Air;
+            if self.grid[next].movable() {
+                if self.grid[sand_pos] != GridTile::SandSource {
+                    self.grid[sand_pos] = GridTile::Air;
                 }
-                self.grid[right.1][right.0] = GridTile::Sand;
-                return Some((right.0, right.1));
+                self.grid[next] = GridTile::Sand;
+                return Some(next);
             }
         }
 
@@ -147,7 +104,7 @@ impl Grid {
     }
 }
 
-fn parse_cave(file: String) -> Grid {
+fn parse_cave(file: String) -> Cave {
     let rock_lines: Vec<Vec<(i32, i32)>> = file
         .lines()
         .map(|line| {
@@ -220,7 +177,7 @@ fn parse_cave(file: String) -> Grid {
     let offset_of_sand_to_left = min_x as isize - sand_spawner.0 as isize;
     let offset_of_sand_to_center = (offset_of_sand_to_left + offset_of_sand_to_right) / 2;
 
-    let mut cave = Grid::new(
+    let mut cave = Cave::new(
         isoscles_c_side as usize,
         height + padding_vertical,
         ((max_x + ((isoscles_c_side as usize - width) / 2)) as isize - offset_of_sand_to_center)
@@ -232,7 +189,7 @@ fn parse_cave(file: String) -> Grid {
     for position in start_items {
         let rel_y = cave.get_relative_y(position.1 as usize);
         let rel_x = cave.get_relative_x(position.0 as usize);
-        cave.grid[rel_y as usize][rel_x as usize] = position.2;
+        cave.grid[Coord::new(rel_x, rel_y)] = position.2;
     }
 
     cave
@@ -240,16 +197,16 @@ fn parse_cave(file: String) -> Grid {
 
 pub fn process_input1(file: String) -> usize {
     let mut cave = parse_cave(file);
-    cave.display();
+    debug!("the cave before the sand falls:\n{cave}");
 
-    let sand_spawner = (cave.get_relative_x(500), cave.get_relative_y(0));
+    let sand_spawner = Coord::new(cave.get_relative_x(500), cave.get_relative_y(0));
     let sand = cave.spawn_sand(sand_spawner).unwrap();
-    let mut sand: Option<(usize, usize)> = cave.move_sand(sand);
+    let mut sand: Option<Coord> = cave.move_sand(sand);
     let mut sand_counter = 0;
 
     while let Some(next_sand_pos) = sand {
         // is the next sand position bottom of the grid
-        if next_sand_pos.1 == cave.grid.len() - 1 {
+        if next_sand_pos.y == cave.grid.height() - 1 {
             break;
         }
         sand = cave.move_sand(next_sand_pos); // recursivly move again until sand cant fall further
@@ -259,7 +216,7 @@ pub fn process_input1(file: String) -> usize {
             sand_counter += 1;
             sand = cave.move_sand(new_sand);
         }
-        cave.display();
+        trace!("after {sand_counter} grains of sand:\n{cave}");
     }
 
     sand_counter
@@ -267,11 +224,11 @@ pub fn process_input1(file: String) -> usize {
 
 pub fn process_input2(file: String) -> usize {
     let mut cave = parse_cave(file);
-    cave.display();
+    debug!("the cave before the sand falls:\n{cave}");
 
-    let sand_spawner = (cave.get_relative_x(500), cave.get_relative_y(0));
+    let sand_spawner = Coord::new(cave.get_relative_x(500), cave.get_relative_y(0));
     let sand = cave.spawn_sand(sand_spawner).unwrap();
-    let mut sand: Option<(usize, usize)> = cave.move_sand(sand);
+    let mut sand: Option<Coord> = cave.move_sand(sand);
     let mut sand_counter = 0;
 
     while let Some(next_sand_pos) = sand {
@@ -287,16 +244,47 @@ pub fn process_input2(file: String) -> usize {
             sand = cave.move_sand(new_sand);
         }
     }
-    cave.display();
+    debug!("the cave after {sand_counter} grains of sand:\n{cave}");
 
     // we return sand_counter + 1, as a sand will not be spawned inside the sand_spawner when all
     // three down positions of it are occupied.
     sand_counter + 1
 }
 
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        Ok(process_input1(input.to_string()))
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = usize;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        Ok(process_input2(input.to_string()))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::assert_snapshot;
 
     #[test]
     fn part_01() {
@@ -309,4 +297,10 @@ mod tests {
         let file = include_str!("test.txt");
         assert_eq!(process_input2(file.to_string()), 93);
     }
+
+    #[test]
+    fn cave_snapshot() {
+        let cave = parse_cave(include_str!("test.txt").to_string());
+        assert_snapshot!("cave", cave.to_string());
+    }
 }
@@ -1,10 +1,12 @@
-use day_15::process_input1;
-use std::fs;
+use aoc_core::{input, Error};
+use day_15::{process_input1, Part1};
 
-fn main() {
-    let file = fs::read_to_string("./input.txt").unwrap();
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
     println!(
         "Output part 01: at row 2_000_000, there are {:?} positions that cannot contain a beacon",
-        process_input1(file, 2_000_000_i64)
+        process_input1(file, 2_000_000_i64)?
     );
+
+    Ok(())
 }
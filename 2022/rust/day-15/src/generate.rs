@@ -0,0 +1,105 @@
+use aoc_core::generate::Rng;
+
+/// `size` random sensors around a single gap in 0..=4_000_000 on both axes, the distress beacon
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    generate_in(rng, size, 4_000_000)
+}
+
+/// Four frame sensors cover everything around the gap `g`, they are diamonds with a radius `r`
+/// larger than the search area which touch in `g` only. With `u = x + y` and `v = x - y` a
+/// diamond is a square, the frames cover `u < g.u`, `u > g.u`, `v < g.v` and `v > g.v`.
+///
+/// The random sensors do not cover the gap. Their beacons are inside the frames, so unlike a real
+/// input a frame sensor is not always closest to its own beacon, which no solver relies on.
+pub fn generate_in(rng: &mut Rng, size: usize, max: i64) -> String {
+    let (gx, gy) = (rng.range(0..=max), rng.range(0..=max));
+    // odd, so the centres are whole positions
+    let r = (3 * max) | 1;
+
+    let (u, v) = (gx + gy, gx - gy);
+    let center = |u: i64, v: i64| ((u + v) / 2, (u - v) / 2);
+    let frames = [
+        (center(u - 1 - r, v), (-r, 0)),
+        (center(u + 1 + r, v), (r, 0)),
+        (center(u, v - 1 - r), (0, r)),
+        (center(u, v + 1 + r), (0, -r)),
+    ];
+    let mut lines: Vec<String> = frames
+        .into_iter()
+        .map(|((x, y), (dx, dy))| line((x, y), (x + dx, y + dy)))
+        .collect();
+
+    while lines.len() < size + frames.len() {
+        let (x, y) = (rng.range(0..=max), rng.range(0..=max));
+        let distance = (x - gx).abs() + (y - gy).abs();
+        if distance < 2 {
+            continue;
+        }
+        let radius = rng.range(1..=(distance - 1).min(max));
+        let dx = rng.range(-radius..=radius);
+        let dy = (radius - dx.abs()) * if rng.chance(50) { 1 } else { -1 };
+        lines.push(line((x, y), (x + dx, y + dy)));
+    }
+
+    rng.shuffle(&mut lines);
+    lines.concat()
+}
+
+fn line((x, y): (i64, i64), (beacon_x, beacon_y): (i64, i64)) -> String {
+    format!("Sensor at x={x}, y={y}: closest beacon is at x={beacon_x}, y={beacon_y}\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parser::parse_to_sensors, process_input1, process_input2, Position};
+    use aoc_parse::parse_all;
+
+    #[test]
+    fn solves_generated_inputs() {
+        let max = 20;
+        // the last row is where an off by one in the search loses the gap
+        let mut gap_in_last_row = false;
+        for seed in 0..50 {
+            let input = generate_in(&mut Rng::new(seed), 8, max);
+            let sensors = parse_all(&input, parse_to_sensors).unwrap();
+            let covered = |position: &Position| {
+                sensors.iter().any(|sensor| {
+                    sensor.manhattan_distance_to_position(position)
+                        <= sensor.manhattan_distance_to_position(&sensor.beacon_position)
+                })
+            };
+
+            let gaps: Vec<Position> = (0..=max)
+                .flat_map(|y| (0..=max).map(move |x| Position { x, y }))
+                .filter(|position| !covered(position))
+                .collect();
+            assert_eq!(gaps.len(), 1, "seed {seed}");
+            let gap = gaps[0];
+            gap_in_last_row |= gap.y == max;
+            assert_eq!(
+                process_input2(input.clone(), max).unwrap(),
+                (gap.x * 4_000_000 + gap.y) as usize,
+                "seed {seed}"
+            );
+
+            let y = seed as i64 % (max + 1);
+            let reach = 4 * (3 * max + 1);
+            let not_beacons = (-reach..=reach)
+                .map(|x| Position { x, y })
+                .filter(|position| {
+                    covered(position)
+                        && sensors.iter().all(|sensor| {
+                            sensor.position != *position && sensor.beacon_position != *position
+                        })
+                })
+                .count();
+            assert_eq!(
+                process_input1(input, y).unwrap(),
+                not_beacons,
+                "seed {seed}"
+            );
+        }
+        assert!(gap_in_last_row);
+    }
+}
@@ -1,7 +1,11 @@
+mod generate;
 mod parser;
+use aoc_core::{debug, Error, Solution};
+use aoc_parse::parse_all;
 use itertools::{self, Itertools};
 use std::{collections::HashSet, ops::RangeInclusive};
 
+pub use generate::{generate, generate_in};
 use parser::parse_to_sensors;
 
 #[derive(Debug, Eq, PartialEq, Hash, Copy, Clone)]
@@ -73,8 +77,8 @@ fn get_sensor_coverage(sensors: &[Sensor], haystack_y: i64) -> Vec<RangeInclusiv
         .fold(vec![], merge_overlapping_sorted_ranges)
 }
 
-pub fn process_input1(file: String, haystack_y: i64) -> usize {
-    let (_, sensors) = parse_to_sensors(&file).unwrap();
+pub fn process_input1(file: String, haystack_y: i64) -> Result<usize, Error> {
+    let sensors = parse_all(&file, parse_to_sensors)?;
     let covered = get_sensor_coverage(&sensors, haystack_y);
     let placed_count = get_placed_items(&sensors, haystack_y).len();
     let covered_count = covered
@@ -82,17 +86,17 @@ pub fn process_input1(file: String, haystack_y: i64) -> usize {
         .map(|range| range.end() - range.start() + 1)
         .sum::<i64>() as usize;
 
-    println!("covered_range {covered:?} sum::{covered_count}");
-    println!("placed_items in y: {placed_count:?}");
+    debug!("covered_range {covered:?} sum::{covered_count}");
+    debug!("placed_items in y: {placed_count:?}");
 
-    covered_count - placed_count
+    Ok(covered_count - placed_count)
 }
 
-pub fn process_input2(file: String, local_maximum: i64) -> usize {
-    let (_, sensors) = parse_to_sensors(&file).unwrap();
+pub fn process_input2(file: String, local_maximum: i64) -> Result<usize, Error> {
+    let sensors = parse_all(&file, parse_to_sensors)?;
 
     // DEBUG: display amount of overlapping ranges in row:
-    // let items: Vec<usize> = (0..local_maximum)
+    // let items: Vec<usize> = (0..=local_maximum)
     //     .map(|y| get_sensor_coverage(&sensors, y).len())
     //     .dedup()
     //     .collect();
@@ -102,14 +106,74 @@ pub fn process_input2(file: String, local_maximum: i64) -> usize {
     // we can get the first y row with two ranges
     // then we get the end of the first range in that y row and go one to the right. That should be
     // the only uncovered position.
-    let (y, x_coverage): (i64, Vec<RangeInclusive<i64>>) = (0..local_maximum)
+    let (y, x_coverage): (i64, Vec<RangeInclusive<i64>>) = (0..=local_maximum)
         .map(|y| (y, get_sensor_coverage(&sensors, y)))
         .find(|(_, range)| range.len() > 1)
-        .unwrap();
+        .ok_or_else(|| {
+            Error::Unsolvable(format!(
+                "every position up to {local_maximum} is covered by a sensor"
+            ))
+        })?;
     let x = x_coverage.first().unwrap().end() + 1;
 
     // calc tuning_frequency
-    x as usize * 4_000_000 + y as usize
+    Ok(x as usize * 4_000_000 + y as usize)
+}
+
+/// the row in which the covered positions are counted
+pub struct Part1Params {
+    pub haystack_y: i64,
+}
+
+impl Default for Part1Params {
+    fn default() -> Self {
+        Self {
+            haystack_y: 2_000_000,
+        }
+    }
+}
+
+/// the distress beacon is somewhere in 0..=local_maximum on both axes
+pub struct Part2Params {
+    pub local_maximum: i64,
+}
+
+impl Default for Part2Params {
+    fn default() -> Self {
+        Self {
+            local_maximum: 4_000_000,
+        }
+    }
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+    const PART: u8 = 1;
+
+    type Params = Part1Params;
+    type Answer = usize;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string(), params.haystack_y)
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+    const PART: u8 = 2;
+
+    type Params = Part2Params;
+    type Answer = usize;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string(), params.local_maximum)
+    }
 }
 
 #[cfg(test)]
@@ -117,14 +181,42 @@ mod tests {
     use super::*;
 
     #[test]
-    fn part_01() {
+    fn part_01() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input1(file.to_string(), 10_i64), 26);
+        assert_eq!(process_input1(file.to_string(), 10_i64)?, 26);
+        Ok(())
     }
 
     #[test]
-    fn part_02() {
+    fn part_02() -> Result<(), Error> {
         let file = include_str!("test.txt");
-        assert_eq!(process_input2(file.to_string(), 20_i64), 56000011);
+        assert_eq!(process_input2(file.to_string(), 20_i64)?, 56000011);
+        Ok(())
+    }
+
+    #[test]
+    fn solution_params() {
+        let file = include_str!("test.txt");
+        assert_eq!(Part1::solve(file, &Part1Params { haystack_y: 10 }), Ok(26));
+        assert_eq!(
+            Part2::solve(file, &Part2Params { local_maximum: 20 }),
+            Ok(56000011)
+        );
+    }
+
+    #[test]
+    fn malformed_sensor() {
+        let file = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\nSensor at x=9, y=16\n";
+        assert_eq!(
+            process_input1(file.to_string(), 10)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 20: expected `: closest beacon is at `"
+        );
+        let file = "Sensor at x=0, y=0: closest beacon is at x=1, y=1\n";
+        assert!(matches!(
+            process_input2(file.to_string(), 1),
+            Err(Error::Unsolvable(_))
+        ));
     }
 }
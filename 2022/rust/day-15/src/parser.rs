@@ -1,42 +1,30 @@
 use crate::{Position, Sensor};
-use nom::{
-    bytes::complete::tag,
-    character::complete::{self, line_ending},
-    multi::separated_list1,
-    sequence::{preceded, separated_pair},
-    IResult,
-};
+use aoc_parse::{lines, tag, xy, IResult};
+use nom::sequence::{preceded, separated_pair};
 
-fn position_parser(input: &str) -> IResult<&str, (i64, i64)> {
-    // parse a single i64 with:
-    // let test = "-14";
-    // let (input, res) = complete::i64(test)?;
-    separated_pair(
-        preceded(tag("x="), complete::i64),
-        tag(", "),
-        preceded(tag("y="), complete::i64),
-    )(input)
+fn position_parser(input: &str) -> IResult<'_, Position> {
+    let (input, (x, y)) = xy(input)?;
+    Ok((input, Position { x, y }))
 }
 
-pub fn line_parser(input: &str) -> IResult<&str, Sensor> {
-    let (input, _) = tag("Sensor at ")(input)?;
-    let (input, sensor_pair) = position_parser(input)?;
-    let (input, _) = tag(": closest beacon is at ")(input)?;
-    let (input, beacon_pair) = position_parser(input)?;
+/// `Sensor at x=2, y=18: closest beacon is at x=-2, y=15`
+pub fn line_parser(input: &str) -> IResult<'_, Sensor> {
+    let (input, (position, beacon_position)) = preceded(
+        tag("Sensor at "),
+        separated_pair(
+            position_parser,
+            tag(": closest beacon is at "),
+            position_parser,
+        ),
+    )(input)?;
 
     let sensor = Sensor {
-        position: Position {
-            x: sensor_pair.0,
-            y: sensor_pair.1,
-        },
-        beacon_position: Position {
-            x: beacon_pair.0,
-            y: beacon_pair.1,
-        },
+        position,
+        beacon_position,
     };
     Ok((input, sensor))
 }
 
-pub fn parse_to_sensors(input: &str) -> IResult<&str, Vec<Sensor>> {
-    separated_list1(line_ending, line_parser)(input)
+pub fn parse_to_sensors(input: &str) -> IResult<'_, Vec<Sensor>> {
+    lines(line_parser)(input)
 }
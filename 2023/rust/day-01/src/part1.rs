@@ -1,35 +1,54 @@
-pub fn solve(input: &str) -> Result<String, String> {
+use aoc_core::{debug, Error, Solution};
+
+pub fn solve(input: &str) -> Result<String, Error> {
     let result: u32 = input
         .trim()
-        .split('\n')
-        .map(|line| {
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
             let first_digit = line
                 .chars()
                 .find(|c| c.is_ascii_digit())
-                .unwrap_or_else(|| panic!("Failed to find first digit in {line}"));
+                .ok_or_else(|| Error::expected(index + 1, "a line with at least one digit"))?;
 
             let last_digit = line
                 .chars()
                 .rev()
                 .find(|c| c.is_ascii_digit())
-                .unwrap_or_else(|| panic!("Failed to find last digit in {line}"));
+                .ok_or_else(|| Error::expected(index + 1, "a line with at least one digit"))?;
 
             format!("{first_digit}{last_digit}")
                 .parse::<u32>()
-                .unwrap_or_else(|_| panic!("couldnt parse {first_digit}{last_digit}"))
+                .map_err(|_| Error::expected(index + 1, "a line with at least one digit"))
         })
-        .sum();
+        .sum::<Result<u32, Error>>()?;
 
-    result.lines().for_each(|line| println!("{}", line));
+    debug!("{result}");
     Ok(result.to_string())
 }
 
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use aoc_core::DynSolution;
 
     #[test]
-    fn test_solve() -> Result<(), String> {
+    fn test_solve() -> Result<(), Error> {
         let input = "1abc2
 pqr3stu8vwx
 a1b2c3d4e5f
@@ -38,4 +57,21 @@ treb7uchet";
         assert_eq!(expected, solve(input)?);
         Ok(())
     }
+
+    #[test]
+    fn test_solve_windows_line_endings() -> Result<(), Error> {
+        let input = "1abc2\r\npqr3stu8vwx\r\na1b2c3d4e5f\r\ntreb7uchet\r\n\r\n";
+        assert_eq!("142", Part1.run(input)?.to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn test_solve_without_digit() {
+        let input = "1abc2
+pqrstu";
+        assert_eq!(
+            solve(input).unwrap_err().to_string(),
+            "line 2: expected a line with at least one digit"
+        );
+    }
 }
@@ -1,4 +1,6 @@
-fn find_digits(line: &str) -> (i32, i32) {
+use aoc_core::{debug, trace, Error, Solution};
+
+fn find_digits(line: &str) -> Option<(i32, i32)> {
     let digits = [
         ("1", 1),
         ("2", 2),
@@ -28,40 +30,56 @@ fn find_digits(line: &str) -> (i32, i32) {
         })
         .collect::<Vec<(i32, usize)>>();
 
-    line_digits_with_position.sort_by(|(_, pos1), (_, pos2)| pos1.cmp(pos2));
+    line_digits_with_position.sort_by_key(|(_, pos1)| *pos1);
 
-    println!("{line} : {:?}", line_digits_with_position);
-    (
-        line_digits_with_position.first().unwrap().0,
-        line_digits_with_position.last().unwrap().0,
-    )
+    trace!("{line} : {:?}", line_digits_with_position);
+    Some((
+        line_digits_with_position.first()?.0,
+        line_digits_with_position.last()?.0,
+    ))
 }
 
-pub fn solve(input: &str) -> Result<String, String> {
+pub fn solve(input: &str) -> Result<String, Error> {
     let result: u32 = input
         .trim()
-        .split('\n')
-        .map(|line| {
-            let (first_digit, last_digit) = find_digits(line);
-            format!("{first_digit}{last_digit}")
+        .lines()
+        .enumerate()
+        .map(|(index, line)| {
+            let (first_digit, last_digit) = find_digits(line)
+                .ok_or_else(|| Error::expected(index + 1, "a line with at least one digit"))?;
+            let line = format!("{first_digit}{last_digit}")
                 .parse::<u32>()
-                .unwrap_or_else(|_| panic!("couldnt parse {first_digit}{last_digit}"))
-        })
-        .map(|line| {
-            println!("{}", line);
-            line
+                .map_err(|_| Error::expected(index + 1, "a line with at least one digit"))?;
+
+            debug!("{}", line);
+            Ok(line)
         })
-        .sum();
+        .sum::<Result<u32, Error>>()?;
 
     Ok(result.to_string())
 }
 
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_solve() -> Result<(), String> {
+    fn test_solve() -> Result<(), Error> {
         let input = "two1nine
 eightwothree
 abcone2threexyz
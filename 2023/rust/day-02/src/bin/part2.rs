@@ -1,8 +1,9 @@
-use day_02::part2::solve;
+use aoc_core::{input, Error};
+use day_02::part2::{solve, Part2};
 
-fn main() -> Result<(), String> {
-    let file = include_str!("../../input2.txt");
-    let result = solve(file)?;
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
+    let result = solve(&file)?;
     println!("Part2: {result}");
 
     Ok(())
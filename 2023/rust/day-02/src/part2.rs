@@ -1,3 +1,5 @@
+use aoc_core::{column_of, debug, Error, Solution};
+
 #[allow(dead_code)]
 #[derive(Debug)]
 struct Game {
@@ -11,47 +13,52 @@ struct Cubes {
     amount: u32,
 }
 
-fn parse(input: &str) -> Vec<Game> {
+fn parse(input: &str) -> Result<Vec<Game>, Error> {
     input
         .lines()
-        .map(|line| {
-            let game_parts = line.split(':').collect::<Vec<&str>>();
-            let id = game_parts[0].split(' ').nth(1).unwrap().to_string(); // Game {id}: ....
-            let sets_parts = game_parts[1].split(';'); // ... {amount} {color}, {amount} {color};
+        .enumerate()
+        .map(|(index, line)| {
+            let line_nr = index + 1;
+            let expected_game = || Error::expected(line_nr, "`Game {id}: {amount} {color}, ...`");
+
+            let (game_part, sets_part) = line.split_once(':').ok_or_else(expected_game)?;
+            let id = game_part // Game {id}: ....
+                .strip_prefix("Game ")
+                .ok_or_else(expected_game)?
+                .to_string();
+            let sets_parts = sets_part.split(';'); // ... {amount} {color}, {amount} {color};
 
             let sets: Vec<Vec<Cubes>> = sets_parts
                 .map(|set| {
                     let cubes_parts = set.split(',');
 
-                    let cubes: Vec<Cubes> = cubes_parts
+                    cubes_parts
                         .map(|cube| {
-                            let mut cube_parts = cube.trim().split(' ');
-                            let amount = cube_parts
-                                .next()
-                                .expect("expected amount Game {id}: {amount} {color}, ...")
-                                .parse::<u32>()
-                                .expect("expected {amount} as u32");
-
-                            let color = cube_parts
-                                .next()
-                                .expect("expected amount Game {id}: {amount} {color}, ...")
-                                .to_string();
-
-                            Cubes { amount, color }
+                            let cube = cube.trim();
+                            let expected_cube = || {
+                                Error::expected(line_nr, "`{amount} {color}`")
+                                    .at_column(column_of(line, cube))
+                            };
+
+                            let (amount, color) = cube.split_once(' ').ok_or_else(expected_cube)?;
+                            let amount = amount.parse::<u32>().map_err(|_| expected_cube())?;
+
+                            Ok(Cubes {
+                                amount,
+                                color: color.to_string(),
+                            })
                         })
-                        .collect();
-
-                    cubes
+                        .collect()
                 })
-                .collect();
+                .collect::<Result<_, Error>>()?;
 
-            Game { id, sets }
+            Ok(Game { id, sets })
         })
         .collect()
 }
 
-pub fn solve(input: &str) -> Result<String, String> {
-    let games = parse(input);
+pub fn solve(input: &str) -> Result<String, Error> {
+    let games = parse(input)?;
 
     let minimum_required_cubes: Vec<Vec<&Cubes>> = games
         .iter()
@@ -99,18 +106,33 @@ pub fn solve(input: &str) -> Result<String, String> {
                 .reduce(|acc, amount| acc * amount)
                 .unwrap()
         })
-        .inspect(|amount| println!("amount: {}", amount))
+        .inspect(|amount| debug!("amount: {}", amount))
         .sum();
 
     Ok(pow_amount_sum.to_string())
 }
 
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_solve() -> Result<(), String> {
+    fn test_solve() -> Result<(), Error> {
         let input = "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
 Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
 Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
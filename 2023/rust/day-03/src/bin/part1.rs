@@ -1,8 +1,9 @@
-use day_03::part1::solve;
+use aoc_core::{input, Error};
+use day_03::part1::{solve, Part1};
 
-fn main() -> Result<(), String> {
-    let file = include_str!("../../input1.txt");
-    let result = solve(file)?;
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    let result = solve(&file)?;
     println!("Part1: {result}");
 
     Ok(())
@@ -1,4 +1,6 @@
-use std::{isize, ops::Range, usize};
+use aoc_core::{prepare::Shape, Error, Solution};
+use aoc_grid::{Coord, Grid};
+use std::ops::Range;
 
 use nom::{
     character::complete::{digit1, one_of},
@@ -39,25 +41,9 @@ fn parse_numbers(line_nr: usize, line: &str) -> IResult<&str, Vec<Number>> {
     ))
 }
 
-fn check_is_symbol(line_nr: isize, x: isize, lines: &[&str]) -> bool {
-    if line_nr < 0 || line_nr > lines.len() as isize - 1 {
-        return false;
-    }
-
-    if let Some(line) = lines.get(line_nr as usize) {
-        if x < 0 || x > line.len() as isize - 1 {
-            return false;
-        }
-
-        if let Some(char) = line.chars().nth(x as usize) {
-            return !matches!(char, '.');
-        }
-    }
-    false
-}
-
-pub fn solve(input: &str) -> Result<String, String> {
+pub fn solve(input: &str) -> Result<String, Error> {
     let lines: Vec<&str> = input.lines().collect();
+    let grid = Grid::from_chars(input)?;
 
     let numbers: Vec<Number> = lines
         .iter()
@@ -68,61 +54,15 @@ pub fn solve(input: &str) -> Result<String, String> {
         })
         .collect();
 
+    // a number is next to a sign if any of the cells around its digits is not a '.'
     let numbers_next_to_sign = numbers.iter().filter(|number| {
-        for x in number.positions.start..=number.positions.end {
-            // check if there is a sign at the top or bottom of the x position in the number
-            let has_top_symbol = check_is_symbol(number.line_nr as isize - 1, x as isize, &lines);
-            let has_bottom_symbol =
-                check_is_symbol(number.line_nr as isize + 1, x as isize, &lines);
-
-            if has_top_symbol || has_bottom_symbol {
-                return true;
-            }
-        }
-        // check if there is a sign left to start or right of end of diagonal
-        let has_left_symbol = check_is_symbol(
-            number.line_nr as isize,
-            number.positions.start as isize - 1,
-            &lines,
-        );
-        let has_left_top_symbol = check_is_symbol(
-            number.line_nr as isize - 1,
-            number.positions.start as isize - 1,
-            &lines,
-        );
-        let has_left_bot_symbol = check_is_symbol(
-            number.line_nr as isize + 1,
-            number.positions.start as isize - 1,
-            &lines,
-        );
-
-        let has_right_symbol = check_is_symbol(
-            number.line_nr as isize,
-            number.positions.end as isize + 1,
-            &lines,
-        );
-        let has_right_top_symbol = check_is_symbol(
-            number.line_nr as isize - 1,
-            number.positions.end as isize + 1,
-            &lines,
-        );
-        let has_right_bot_symbol = check_is_symbol(
-            number.line_nr as isize + 1,
-            number.positions.end as isize + 1,
-            &lines,
-        );
-
-        if has_left_symbol
-            || has_left_top_symbol
-            || has_left_bot_symbol
-            || has_right_symbol
-            || has_right_top_symbol
-            || has_right_bot_symbol
-        {
-            return true;
-        }
-
-        false
+        let digits = number.positions.start..=number.positions.end;
+
+        digits.clone().any(|x| {
+            grid.neighbours8(Coord::new(x, number.line_nr))
+                .filter(|coord| coord.y != number.line_nr || !digits.contains(&coord.x))
+                .any(|coord| grid[coord] != '.')
+        })
     });
 
     let sum_of_numbers = numbers_next_to_sign.map(|number| number.value).sum::<u32>();
@@ -130,12 +70,28 @@ pub fn solve(input: &str) -> Result<String, String> {
     Ok(sum_of_numbers.to_string())
 }
 
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = String;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_solve() -> Result<(), String> {
+    fn test_solve() -> Result<(), Error> {
         let input = "467..114..
 ...*......
 ..35..633.
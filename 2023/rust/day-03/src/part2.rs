@@ -1,13 +1,31 @@
-pub fn solve(_input: &str) -> Result<String, String> {
+use aoc_core::{prepare::Shape, Error, Solution};
+
+pub fn solve(_input: &str) -> Result<String, Error> {
     todo!("day 01 - part 2");
 }
 
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+    const SHAPE: Shape = Shape::Grid;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
-    fn test_solve() -> Result<(), String> {
+    fn test_solve() -> Result<(), Error> {
         let input = "";
         let expected = "";
         assert_eq!(expected, solve(input)?);
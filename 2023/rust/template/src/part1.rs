@@ -1,16 +0,0 @@
-pub fn solve(_input: &str) -> Result<String, String> {
-    todo!("day 01 - part 1");
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_solve() -> Result<(), String> {
-        let input = "";
-        let expected = "";
-        assert_eq!(expected, solve(input)?);
-        Ok(())
-    }
-}
@@ -0,0 +1,123 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The answer of a part in the form the puzzle asks for it, with an optional explanation for
+/// people reading it. The explanation is never part of the displayed answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answer {
+    pub value: Value,
+    pub explanation: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    Integer(i128),
+    Text(String),
+    /// a picture drawn in rows of chars, like the crt of 2022 day-10
+    Grid(String),
+}
+
+impl Answer {
+    pub fn grid(rows: impl Into<String>) -> Self {
+        Value::Grid(rows.into()).into()
+    }
+
+    pub fn explained(self, explanation: impl Into<String>) -> Self {
+        Answer {
+            explanation: Some(explanation.into()),
+            ..self
+        }
+    }
+
+    /// the answer as a json value: a number, or a string for text and grids
+    pub fn to_json(&self) -> String {
+        match &self.value {
+            Value::Integer(integer) => integer.to_string(),
+            Value::Text(text) | Value::Grid(text) => json_string(text),
+        }
+    }
+}
+
+/// `text` as a quoted json string
+pub fn json_string(text: &str) -> String {
+    let mut json = String::from('"');
+    for char in text.chars() {
+        match char {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            char if char.is_control() => json.push_str(&format!("\\u{:04x}", char as u32)),
+            char => json.push(char),
+        }
+    }
+    json.push('"');
+    json
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Value::Integer(integer) => write!(f, "{integer}"),
+            Value::Text(text) | Value::Grid(text) => write!(f, "{text}"),
+        }
+    }
+}
+
+impl From<Value> for Answer {
+    fn from(value: Value) -> Self {
+        Answer {
+            value,
+            explanation: None,
+        }
+    }
+}
+
+macro_rules! integer_answers {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(integer: $integer) -> Self {
+                    Value::Integer(integer as i128).into()
+                }
+            }
+        )*
+    };
+}
+
+integer_answers!(i32, u32, i64, u64, isize, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Value::Text(text).into()
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        text.to_string().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_leaves_out_the_explanation() {
+        let answer = Answer::from(24000_usize).explained("elf 4 carries the most calories");
+        assert_eq!(answer.to_string(), "24000");
+        assert_eq!(answer.to_json(), "24000");
+        assert_eq!(
+            answer.explanation.as_deref(),
+            Some("elf 4 carries the most calories")
+        );
+    }
+
+    #[test]
+    fn json() {
+        assert_eq!(Answer::from("CMZ").to_json(), r#""CMZ""#);
+        assert_eq!(Answer::grid("#.\n.#").to_json(), r##""#.\n.#""##);
+        assert_eq!(json_string("a \"b\" \\ \u{1}"), r#""a \"b\" \\ \u0001""#);
+    }
+}
@@ -0,0 +1,118 @@
+use std::fmt::{self, Display, Formatter};
+
+use crate::input::InputError;
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+/// Everything that can go wrong while solving a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// the input does not match the format of the puzzle. `line` and `column` start at 1, the
+    /// column is only known by parsers that look into the line.
+    Parse {
+        line: usize,
+        column: Option<usize>,
+        expected: String,
+    },
+    /// the input could be parsed, but the puzzle has no answer for it
+    Unsolvable(String),
+    /// the params of a part do not make sense, whatever the input is
+    Params(String),
+    /// the input could not be found or read
+    Input(InputError),
+}
+
+impl Error {
+    /// eg. `Error::expected(17, "`move N from A to B`")`
+    pub fn expected(line: usize, expected: impl Into<String>) -> Self {
+        Error::Parse {
+            line,
+            column: None,
+            expected: expected.into(),
+        }
+    }
+
+    pub fn at_column(self, column: usize) -> Self {
+        match self {
+            Error::Parse { line, expected, .. } => Error::Parse {
+                line,
+                column: Some(column),
+                expected,
+            },
+            error => error,
+        }
+    }
+
+    /// parsers of a single line (like `FromStr` impls) report line 1. The caller knows which line
+    /// it handed over and moves the error onto it.
+    pub fn on_line(self, line: usize) -> Self {
+        match self {
+            Error::Parse {
+                column, expected, ..
+            } => Error::Parse {
+                line,
+                column,
+                expected,
+            },
+            error => error,
+        }
+    }
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column: Some(column),
+                expected,
+            } => write!(f, "line {line}, column {column}: expected {expected}"),
+            Error::Parse {
+                line,
+                column: None,
+                expected,
+            } => write!(f, "line {line}: expected {expected}"),
+            Error::Unsolvable(reason) => write!(f, "no solution: {reason}"),
+            Error::Params(reason) => write!(f, "invalid params: {reason}"),
+            Error::Input(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<InputError> for Error {
+    fn from(err: InputError) -> Self {
+        Error::Input(err)
+    }
+}
+
+/// 1 based column at which `part` starts inside of `line`. `part` has to be a subslice of `line`.
+pub fn column_of(line: &str, part: &str) -> usize {
+    part.as_ptr() as usize - line.as_ptr() as usize + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        let error = Error::expected(1, "`move N from A to B`").on_line(17);
+        assert_eq!(error.to_string(), "line 17: expected `move N from A to B`");
+        assert_eq!(
+            error.at_column(6).to_string(),
+            "line 17, column 6: expected `move N from A to B`"
+        );
+        assert_eq!(
+            Error::Params("no elves".to_string()).to_string(),
+            "invalid params: no elves"
+        );
+    }
+
+    #[test]
+    fn column() {
+        let line = "Game 1: 3 blue";
+        assert_eq!(column_of(line, &line[8..]), 9);
+    }
+}
@@ -0,0 +1,259 @@
+//! Finds the puzzle input of a day.
+//!
+//! An explicit path always wins, `-` reads stdin. Otherwise the input is looked up in the
+//! directory named by `AOC_INPUT_DIR` as `{year}/day-{DD}-part-{P}.txt` or `{year}/day-{DD}.txt`,
+//! and last inside of the days crate as `input{P}.txt` or `input.txt`.
+
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, BufRead, BufReader, Read},
+    path::{Path, PathBuf},
+};
+
+use crate::{prepare, Solution};
+
+/// the directory with the inputs of every year
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    /// none of the `tried` files exists
+    NotFound {
+        tried: Vec<PathBuf>,
+    },
+    Read {
+        source: Source,
+        reason: String,
+    },
+    /// a solver streaming its input could not read the line after `line`
+    Stream {
+        line: usize,
+        reason: String,
+    },
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "stdin"),
+            Source::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::NotFound { tried } => {
+                write!(f, "no input found, tried")?;
+                for path in tried {
+                    write!(f, " {}", path.display())?;
+                }
+                write!(
+                    f,
+                    ". Pass the input as a path, `-` for stdin or set {INPUT_DIR_VAR}"
+                )
+            }
+            InputError::Read { source, reason } => write!(f, "could not read {source}: {reason}"),
+            InputError::Stream { line, reason } => {
+                write!(f, "could not read the input after line {line}: {reason}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl Source {
+    pub fn read(&self) -> Result<String, InputError> {
+        let read_error = |err: io::Error| InputError::Read {
+            source: self.clone(),
+            reason: err.to_string(),
+        };
+
+        match self {
+            Source::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input).map_err(read_error)?;
+                Ok(input)
+            }
+            Source::File(path) => fs::read_to_string(path).map_err(read_error),
+        }
+    }
+
+    /// the source as a reader for solvers that stream their input
+    pub fn open(&self) -> Result<Box<dyn BufRead>, InputError> {
+        match self {
+            Source::Stdin => Ok(Box::new(io::stdin().lock())),
+            Source::File(path) => {
+                let file = fs::File::open(path).map_err(|err| InputError::Read {
+                    source: self.clone(),
+                    reason: err.to_string(),
+                })?;
+                Ok(Box::new(BufReader::new(file)))
+            }
+        }
+    }
+}
+
+/// the files that may hold the input of a part, in the order they are tried
+pub fn candidates(
+    input_dir: Option<&Path>,
+    crate_dir: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Vec<PathBuf> {
+    let mut candidates = vec![];
+
+    if let Some(input_dir) = input_dir {
+        let year_dir = input_dir.join(year.to_string());
+        candidates.push(year_dir.join(format!("day-{day:02}-part-{part}.txt")));
+        candidates.push(year_dir.join(format!("day-{day:02}.txt")));
+    }
+    candidates.push(crate_dir.join(format!("input{part}.txt")));
+    candidates.push(crate_dir.join("input.txt"));
+
+    candidates
+}
+
+/// `arg` is the path given on the command line, the first existing candidate is used without one
+pub fn resolve(arg: Option<&str>, candidates: &[PathBuf]) -> Result<Source, InputError> {
+    match arg {
+        Some("-") => Ok(Source::Stdin),
+        Some(path) => Ok(Source::File(PathBuf::from(path))),
+        None => candidates
+            .iter()
+            .find(|path| path.is_file())
+            .map(|path| Source::File(path.clone()))
+            .ok_or_else(|| InputError::NotFound {
+                tried: candidates.to_vec(),
+            }),
+    }
+}
+
+/// like [`resolve`], looking into `AOC_INPUT_DIR` if it is set
+pub fn locate(
+    arg: Option<&str>,
+    crate_dir: &Path,
+    year: u16,
+    day: u8,
+    part: u8,
+) -> Result<Source, InputError> {
+    let input_dir = env::var_os(INPUT_DIR_VAR).map(PathBuf::from);
+    resolve(
+        arg,
+        &candidates(input_dir.as_deref(), crate_dir, year, day, part),
+    )
+}
+
+/// reads the input of `S` for a days binary, which passes its `env!("CARGO_MANIFEST_DIR")`. The
+/// first command line argument is the explicit path. The input is [prepared](prepare::prepare).
+pub fn load<S: Solution>(crate_dir: &str) -> Result<String, InputError> {
+    let arg = env::args().nth(1);
+    let input = locate(
+        arg.as_deref(),
+        Path::new(crate_dir),
+        S::YEAR,
+        S::DAY,
+        S::PART,
+    )?
+    .read()?;
+    Ok(prepare::prepare::<S>(&input))
+}
+
+/// like [`load`] for a solver streaming its input, which is not prepared
+pub fn open<S: Solution>(crate_dir: &str) -> Result<Box<dyn BufRead>, InputError> {
+    let arg = env::args().nth(1);
+    locate(
+        arg.as_deref(),
+        Path::new(crate_dir),
+        S::YEAR,
+        S::DAY,
+        S::PART,
+    )?
+    .open()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// an empty directory in the systems temp dir
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-input-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn explicit_path_and_stdin() {
+        assert_eq!(
+            resolve(Some("my-input.txt"), &[]),
+            Ok(Source::File(PathBuf::from("my-input.txt")))
+        );
+        assert_eq!(resolve(Some("-"), &[]), Ok(Source::Stdin));
+    }
+
+    #[test]
+    fn input_dir_before_the_crate() {
+        let dir = temp_dir("order");
+        let crate_dir = dir.join("day-04");
+        fs::create_dir_all(dir.join("inputs/2022")).unwrap();
+        fs::create_dir_all(&crate_dir).unwrap();
+        fs::write(crate_dir.join("input.txt"), "crate").unwrap();
+
+        let inputs = dir.join("inputs");
+        let candidates = candidates(Some(&inputs), &crate_dir, 2022, 4, 1);
+        assert_eq!(
+            resolve(None, &candidates),
+            Ok(Source::File(crate_dir.join("input.txt")))
+        );
+
+        fs::write(inputs.join("2022/day-04.txt"), "shared").unwrap();
+        let source = resolve(None, &candidates).unwrap();
+        assert_eq!(source, Source::File(inputs.join("2022/day-04.txt")));
+        assert_eq!(source.read(), Ok("shared".to_string()));
+        let mut streamed = String::new();
+        source
+            .open()
+            .unwrap()
+            .read_to_string(&mut streamed)
+            .unwrap();
+        assert_eq!(streamed, "shared");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn missing_input() {
+        let dir = temp_dir("missing");
+        let error = resolve(None, &candidates(None, &dir, 2023, 1, 2)).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "no input found, tried {} {}. Pass the input as a path, `-` for stdin or set AOC_INPUT_DIR",
+                dir.join("input2.txt").display(),
+                dir.join("input.txt").display()
+            )
+        );
+
+        let source = Source::File(dir.join("nope.txt"));
+        assert!(source.open().is_err());
+        assert!(source.read().unwrap_err().to_string().starts_with(&format!(
+            "could not read {}: ",
+            dir.join("nope.txt").display()
+        )));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,13 @@
+mod answer;
+mod error;
+pub mod generate;
+pub mod input;
+pub mod log;
+pub mod prepare;
+pub mod snapshot;
+mod solution;
+pub mod tasks;
+
+pub use answer::{json_string, Answer, Value};
+pub use error::{column_of, Error, Result};
+pub use solution::{DynSolution, Solution};
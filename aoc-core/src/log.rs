@@ -0,0 +1,236 @@
+//! Diagnostics of the solutions, printed to stderr by the [`warn!`](crate::warn),
+//! [`info!`](crate::info), [`debug!`](crate::debug) and [`trace!`](crate::trace) macros.
+//!
+//! The level is chosen per day with a filter like `debug` or `info,2022-14=trace`. The runner
+//! takes it as `--log`, the days binaries read $AOC_LOG. Built with the `bench` feature, the
+//! macros compile to nothing.
+
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    fmt::{self, Arguments, Display, Formatter},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        RwLock,
+    },
+};
+
+/// false in benchmark builds, which leaves every log macro empty
+pub const ENABLED: bool = cfg!(not(feature = "bench"));
+
+/// the filter of binaries which do not set one
+pub const LOG_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+const LEVELS: [Level; 5] = [
+    Level::Off,
+    Level::Warn,
+    Level::Info,
+    Level::Debug,
+    Level::Trace,
+];
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Warn => "warn",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{name}")
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        LEVELS
+            .into_iter()
+            .find(|level| level.to_string() == s)
+            .ok_or_else(|| format!("unknown log level `{s}`"))
+    }
+}
+
+/// The level of every day, eg. `info,2022-14=trace` logs day 14 of 2022 down to trace and
+/// every other day down to info.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Filter {
+    default: Level,
+    days: BTreeMap<(u16, u8), Level>,
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        Filter {
+            default: Level::Warn,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Filter::default();
+
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            let Some((puzzle, level)) = directive.split_once('=') else {
+                filter.default = directive.parse()?;
+                continue;
+            };
+
+            let expected = || format!("expected `{{year}}-{{day}}={{level}}`, got `{directive}`");
+            let (year, day) = puzzle.split_once('-').ok_or_else(expected)?;
+            let year = year.parse().map_err(|_| expected())?;
+            let day = day.parse().map_err(|_| expected())?;
+            filter.days.insert((year, day), level.parse()?);
+        }
+
+        Ok(filter)
+    }
+}
+
+impl Filter {
+    /// the level of a day, `None` outside of a puzzle
+    pub fn level(&self, puzzle: Option<(u16, u8)>) -> Level {
+        puzzle
+            .and_then(|puzzle| self.days.get(&puzzle).copied())
+            .unwrap_or(self.default)
+    }
+
+    fn max_level(&self) -> Level {
+        self.days.values().copied().fold(self.default, Level::max)
+    }
+}
+
+static FILTER: RwLock<Option<Filter>> = RwLock::new(None);
+/// the highest level of the filter, which lets most disabled calls skip the lock
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(u8::MAX);
+
+thread_local! {
+    static PUZZLE: Cell<Option<(u16, u8)>> = const { Cell::new(None) };
+}
+
+pub fn set_filter(filter: Filter) {
+    MAX_LEVEL.store(filter.max_level() as u8, Ordering::Relaxed);
+    *FILTER.write().unwrap_or_else(|err| err.into_inner()) = Some(filter);
+}
+
+/// runs `f` as part of a day, so its logs use the level of the day
+pub fn in_puzzle<T>(year: u16, day: u8, f: impl FnOnce() -> T) -> T {
+    let outer = PUZZLE.replace(Some((year, day)));
+    let result = f();
+    PUZZLE.set(outer);
+    result
+}
+
+pub fn enabled(level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+
+    let filter = FILTER.read().unwrap_or_else(|err| err.into_inner());
+    match filter.as_ref() {
+        Some(filter) => level <= filter.level(PUZZLE.get()),
+        None => {
+            drop(filter);
+            // nobody has set a filter, it comes from the environment then
+            let filter = std::env::var(LOG_VAR)
+                .ok()
+                .and_then(|filter| filter.parse().ok())
+                .unwrap_or_default();
+            set_filter(filter);
+            enabled(level)
+        }
+    }
+}
+
+pub fn write(level: Level, args: Arguments) {
+    match PUZZLE.get() {
+        Some((year, day)) => eprintln!("[{level} {year} day {day:02}] {args}"),
+        None => eprintln!("[{level}] {args}"),
+    }
+}
+
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::ENABLED && $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+/// something looks wrong, but the solution carries on
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+/// the details of every step, like a picture of the cave after each grain of sand
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_filter() {
+        let filter: Filter = "info, 2022-14=trace,2023-1=off".parse().unwrap();
+        assert_eq!(filter.level(None), Level::Info);
+        assert_eq!(filter.level(Some((2022, 14))), Level::Trace);
+        assert_eq!(filter.level(Some((2023, 1))), Level::Off);
+        assert_eq!(filter.level(Some((2022, 15))), Level::Info);
+        assert_eq!(filter.max_level(), Level::Trace);
+
+        assert_eq!("".parse::<Filter>(), Ok(Filter::default()));
+        assert_eq!(
+            "loud".parse::<Filter>(),
+            Err("unknown log level `loud`".to_string())
+        );
+        assert_eq!(
+            "2022=debug".parse::<Filter>(),
+            Err("expected `{year}-{day}={level}`, got `2022=debug`".to_string())
+        );
+    }
+
+    #[test]
+    fn level_of_the_current_puzzle() {
+        set_filter("off,2022-14=debug".parse().unwrap());
+
+        assert!(!enabled(Level::Warn));
+        in_puzzle(2022, 14, || {
+            assert!(enabled(Level::Debug));
+            assert!(!enabled(Level::Trace));
+            in_puzzle(2022, 15, || assert!(!enabled(Level::Warn)));
+            assert!(enabled(Level::Info));
+        });
+        assert!(!enabled(Level::Info));
+    }
+}
@@ -0,0 +1,205 @@
+//! Normalizes a puzzle input before it reaches a solver and warns about what looks odd in it.
+//!
+//! Inputs saved on windows or pasted from a browser differ in small ways: `\r\n` line endings,
+//! spaces at the end of lines, a missing or doubled final newline. Every solver gets the input
+//! with `\n` line endings, no trailing whitespace and exactly one final newline instead.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{log, warn, Solution};
+
+/// the layout a solver expects its input in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Shape {
+    Text,
+    /// rows of the same width, like the height map of 2022 day-12
+    Grid,
+}
+
+/// something in an input which is likely a mistake, but is left for the solver to deal with
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Anomaly {
+    Tabs {
+        first_line: usize,
+        lines: usize,
+    },
+    NonAscii {
+        first_line: usize,
+        char: char,
+        lines: usize,
+    },
+    /// a row of a grid which is not as wide as the first one
+    RaggedRow {
+        line: usize,
+        width: usize,
+        expected: usize,
+    },
+}
+
+impl Display for Anomaly {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::Tabs { first_line, lines } => {
+                write!(f, "{lines} line(s) contain tabs, the first is line {first_line}")
+            }
+            Anomaly::NonAscii {
+                first_line,
+                char,
+                lines,
+            } => write!(
+                f,
+                "{lines} line(s) contain non-ascii chars, the first is {char:?} on line {first_line}"
+            ),
+            Anomaly::RaggedRow {
+                line,
+                width,
+                expected,
+            } => write!(
+                f,
+                "row {line} of the grid is {width} wide, the rows above are {expected} wide"
+            ),
+        }
+    }
+}
+
+/// `\n` line endings, no whitespace at the end of lines, no empty lines at the end and a single
+/// final newline. Leading whitespace is kept, some drawings (2022 day-05) depend on it.
+pub fn normalize(input: &str) -> String {
+    let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+
+    let mut normalized = input
+        .replace("\r\n", "\n")
+        .replace('\r', "\n")
+        .split('\n')
+        .map(str::trim_end)
+        .collect::<Vec<_>>()
+        .join("\n");
+    normalized.truncate(normalized.trim_end_matches('\n').len());
+    if !normalized.is_empty() {
+        normalized.push('\n');
+    }
+    normalized
+}
+
+/// the anomalies of a normalized input, one of every kind
+pub fn lint(input: &str, shape: Shape) -> Vec<Anomaly> {
+    let mut anomalies = vec![];
+
+    let tabs: Vec<usize> = line_numbers(input, |line| line.contains('\t'));
+    if let Some(&first_line) = tabs.first() {
+        anomalies.push(Anomaly::Tabs {
+            first_line,
+            lines: tabs.len(),
+        });
+    }
+
+    let non_ascii = line_numbers(input, |line| !line.is_ascii());
+    if let Some(&first_line) = non_ascii.first() {
+        let line = input.lines().nth(first_line - 1).unwrap_or_default();
+        anomalies.push(Anomaly::NonAscii {
+            first_line,
+            char: line
+                .chars()
+                .find(|char| !char.is_ascii())
+                .unwrap_or_default(),
+            lines: non_ascii.len(),
+        });
+    }
+
+    if shape == Shape::Grid {
+        let mut rows = input
+            .lines()
+            .enumerate()
+            .filter(|(_, row)| !row.is_empty())
+            .map(|(index, row)| (index + 1, row.chars().count()));
+
+        if let Some((_, expected)) = rows.next() {
+            if let Some((line, width)) = rows.find(|&(_, width)| width != expected) {
+                anomalies.push(Anomaly::RaggedRow {
+                    line,
+                    width,
+                    expected,
+                });
+            }
+        }
+    }
+
+    anomalies
+}
+
+fn line_numbers(input: &str, matches: impl Fn(&str) -> bool) -> Vec<usize> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| matches(line))
+        .map(|(index, _)| index + 1)
+        .collect()
+}
+
+/// the input as `S` gets it: normalized, with a warning for every anomaly
+pub fn prepare<S: Solution>(input: &str) -> String {
+    let input = normalize(input);
+    log::in_puzzle(S::YEAR, S::DAY, || {
+        for anomaly in lint(&input, S::SHAPE) {
+            warn!("{anomaly}");
+        }
+    });
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings_and_whitespace() {
+        assert_eq!(
+            normalize("1000\r\n2000 \r\n\r\n3000\t\r\n\r\n"),
+            "1000\n2000\n\n3000\n"
+        );
+        assert_eq!(normalize("\u{feff}A Y\nB X"), "A Y\nB X\n");
+        assert_eq!(normalize("a\rb\n\n\n"), "a\nb\n");
+        assert_eq!(
+            normalize("    [D]    \n[N] [C]    \n"),
+            "    [D]\n[N] [C]\n"
+        );
+        assert_eq!(normalize("\n\n"), "");
+    }
+
+    #[test]
+    fn lints() {
+        assert_eq!(lint("30373\n25512\n", Shape::Grid), vec![]);
+        assert_eq!(
+            lint("30373\n2551\n65332\n", Shape::Grid),
+            vec![Anomaly::RaggedRow {
+                line: 2,
+                width: 4,
+                expected: 5
+            }]
+        );
+        assert_eq!(lint("30373\n2551\n", Shape::Text), vec![]);
+        assert_eq!(
+            lint("1\ta\n2\n3\tb\nSabqponm→\n", Shape::Text),
+            vec![
+                Anomaly::Tabs {
+                    first_line: 1,
+                    lines: 2
+                },
+                Anomaly::NonAscii {
+                    first_line: 4,
+                    char: '→',
+                    lines: 1
+                }
+            ]
+        );
+        assert_eq!(
+            Anomaly::RaggedRow {
+                line: 2,
+                width: 4,
+                expected: 5
+            }
+            .to_string(),
+            "row 2 of the grid is 4 wide, the rows above are 5 wide"
+        );
+    }
+}
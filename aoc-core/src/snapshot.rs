@@ -0,0 +1,142 @@
+//! Snapshot tests of multi-line renders, like the crt of 2022 day-10 or a cave of day-14.
+//!
+//! [`assert_snapshot!`](crate::assert_snapshot) compares a render with the one saved in
+//! `snapshots/{name}.snap` next to the crates Cargo.toml and fails with a line diff if they
+//! differ. After an intentional change, run the tests with `AOC_ACCEPT=1` to save the new renders.
+
+use std::{env, fs, io, path::Path};
+
+/// set to anything but `0` to save the renders instead of comparing them
+pub const ACCEPT_VAR: &str = "AOC_ACCEPT";
+
+pub const SNAPSHOT_DIR: &str = "snapshots";
+
+fn accept_mode() -> bool {
+    env::var_os(ACCEPT_VAR).is_some_and(|accept| accept != "0")
+}
+
+/// compares `actual` with the snapshot `name` of the crate at `crate_dir`, or saves it in accept
+/// mode. Trailing newlines are ignored.
+#[track_caller]
+pub fn assert_snapshot(crate_dir: &str, name: &str, actual: &str) {
+    if let Err(message) = check(Path::new(crate_dir), name, actual, accept_mode()) {
+        panic!("{message}");
+    }
+}
+
+fn check(crate_dir: &Path, name: &str, actual: &str, accept: bool) -> Result<(), String> {
+    let path = crate_dir.join(SNAPSHOT_DIR).join(format!("{name}.snap"));
+    let actual = actual.trim_end_matches('\n');
+
+    if accept {
+        fs::create_dir_all(crate_dir.join(SNAPSHOT_DIR))
+            .and_then(|_| fs::write(&path, format!("{actual}\n")))
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        return Ok(());
+    }
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) => expected,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            return Err(format!(
+                "there is no snapshot {}, run the test with {ACCEPT_VAR}=1 to save it:\n{actual}",
+                path.display()
+            ));
+        }
+        Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+    };
+    let expected = expected.trim_end_matches('\n');
+
+    if expected == actual {
+        return Ok(());
+    }
+    Err(format!(
+        "the render differs from {} (- saved, + actual), run the test with {ACCEPT_VAR}=1 \
+         if the change is intended:\n{}",
+        path.display(),
+        diff(expected, actual)
+    ))
+}
+
+/// the lines of both texts, the ones only in `expected` start with `-`, the ones only in
+/// `actual` with `+`
+pub fn diff(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+
+    // common[i][j] is the length of the longest common subsequence of expected[i..] and actual[j..]
+    let mut common = vec![vec![0; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            lines.push(format!("  {}", expected[i]));
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            lines.push(format!("- {}", expected[i]));
+            i += 1;
+        } else {
+            lines.push(format!("+ {}", actual[j]));
+            j += 1;
+        }
+    }
+    lines.join("\n")
+}
+
+/// `assert_snapshot!("crt", render)` compares `render` with snapshots/crt.snap of the calling crate
+#[macro_export]
+macro_rules! assert_snapshot {
+    ($name:expr, $actual:expr) => {
+        $crate::snapshot::assert_snapshot(env!("CARGO_MANIFEST_DIR"), $name, &$actual)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn line_diff() {
+        assert_eq!(
+            diff("#..\n.#.\n..#", "#..\n.#.\n..#"),
+            "  #..\n  .#.\n  ..#"
+        );
+        assert_eq!(
+            diff("#..\n.#.\n..#", "#..\n.##\n..#\n..."),
+            "  #..\n- .#.\n+ .##\n  ..#\n+ ..."
+        );
+    }
+
+    #[test]
+    fn accept_then_compare() {
+        let dir = env::temp_dir().join(format!("aoc-snapshot-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let error = check(&dir, "cave", "#.\n.#\n", false).unwrap_err();
+        assert!(error.starts_with("there is no snapshot"));
+
+        check(&dir, "cave", "#.\n.#\n", true).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("snapshots/cave.snap")).unwrap(),
+            "#.\n.#\n"
+        );
+        assert_eq!(check(&dir, "cave", "#.\n.#", false), Ok(()));
+
+        let error = check(&dir, "cave", "#.\n##", false).unwrap_err();
+        assert!(error.ends_with("if the change is intended:\n  #.\n- .#\n+ ##"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{log, prepare, prepare::Shape, Answer, Error};
+
+/// A single part of a days puzzle.
+///
+/// Every day implements this once for `Part1` and once for `Part2`, so tooling like the `aoc`
+/// runner can call all of them the same way.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+    const PART: u8;
+
+    /// extra arguments the puzzle needs besides the input, like the amount of rounds to simulate
+    /// (2022 day-11). Days without any use `()`.
+    type Params: Default;
+    /// usually a number or a `String`, or an [`Answer`] with an explanation
+    type Answer: Into<Answer>;
+    /// grids get their rows checked for the same width before solving
+    const SHAPE: Shape = Shape::Text;
+
+    fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error>;
+}
+
+/// Object safe version of [`Solution`], solving with the default params.
+pub trait DynSolution: Sync {
+    fn year(&self) -> u16;
+    fn day(&self) -> u8;
+    fn part(&self) -> u8;
+    /// the input as the solution expects it, see [`prepare::prepare`]
+    fn prepare(&self, input: &str) -> String;
+    /// solves an input which went through [`prepare`](DynSolution::prepare) already
+    fn solve_prepared(&self, input: &str) -> Result<Answer, Error>;
+
+    /// solves the [prepared](prepare::prepare) input
+    fn run(&self, input: &str) -> Result<Answer, Error> {
+        self.solve_prepared(&self.prepare(input))
+    }
+}
+
+impl<S: Solution + Sync> DynSolution for S {
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn part(&self) -> u8 {
+        S::PART
+    }
+
+    fn prepare(&self, input: &str) -> String {
+        log::in_puzzle(S::YEAR, S::DAY, || prepare::prepare::<S>(input))
+    }
+
+    fn solve_prepared(&self, input: &str) -> Result<Answer, Error> {
+        log::in_puzzle(S::YEAR, S::DAY, || {
+            S::solve(input, &S::Params::default()).map(Into::into)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Rounds(usize);
+
+    impl Default for Rounds {
+        fn default() -> Self {
+            Self(3)
+        }
+    }
+
+    struct Example;
+
+    impl Solution for Example {
+        const YEAR: u16 = 2022;
+        const DAY: u8 = 11;
+        const PART: u8 = 1;
+
+        type Params = Rounds;
+        type Answer = usize;
+
+        fn solve(input: &str, params: &Self::Params) -> Result<Self::Answer, Error> {
+            Ok(input.len() * params.0)
+        }
+    }
+
+    #[test]
+    fn dyn_solution_uses_default_params() {
+        let solution: &dyn DynSolution = &Example;
+        assert_eq!(
+            (solution.year(), solution.day(), solution.part()),
+            (2022, 11, 1)
+        );
+        // the prepared input is "ab\n"
+        assert_eq!(solution.run("ab\r\n"), Ok(Answer::from(9_usize)));
+        assert_eq!(solution.prepare("ab\r\n"), "ab\n");
+        assert_eq!(solution.solve_prepared("ab"), Ok(Answer::from(6_usize)));
+        assert_eq!(Example::solve("ab", &Rounds(1)), Ok(2));
+    }
+}
@@ -0,0 +1,259 @@
+//! Reads the puzzle texts saved in a years tasks.md.
+//!
+//! Example inputs are fenced code blocks or inline code spans, the answer the puzzle gives for an
+//! example is written in bold (`**24000**`) or as a fenced code block tagged `answer`. Every
+//! answer belongs to the closest example input above it, which may be in an earlier part of the
+//! same day.
+
+use crate::{prepare, Answer, Solution};
+
+/// A days puzzle text as it is saved in tasks.md.
+#[derive(Debug, PartialEq)]
+pub struct Puzzle {
+    pub day: u8,
+    pub title: String,
+    pub parts: Vec<PuzzlePart>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct PuzzlePart {
+    pub text: String,
+    /// the accepted answer, if the part was solved when the text was copied
+    pub answer: Option<String>,
+    pub examples: Vec<Example>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    pub input: String,
+    pub expected: String,
+}
+
+const ANSWER_PREFIX: &str = "Your puzzle answer was ";
+const FENCE: &str = "```";
+
+/// parses the "--- Day 1: Calorie Counting ---" header into (1, "Calorie Counting")
+fn parse_day_header(line: &str) -> Option<(u8, String)> {
+    let header = line.strip_prefix("--- Day ")?.strip_suffix(" ---")?;
+    let (day, title) = header.split_once(": ")?;
+    Some((day.parse().ok()?, title.to_string()))
+}
+
+/// collects the inline code spans and bold answers of a line in the order they appear
+fn scan_line(line: &str, input: &mut Option<String>, examples: &mut Vec<Example>) {
+    let mut rest = line;
+
+    loop {
+        let code = rest.find('`');
+        let bold = rest.find("**");
+
+        let (start, marker) = match (code, bold) {
+            (Some(code), Some(bold)) if code < bold => (code, "`"),
+            (_, Some(bold)) => (bold, "**"),
+            (Some(code), None) => (code, "`"),
+            (None, None) => return,
+        };
+
+        let after = &rest[start + marker.len()..];
+        let Some(end) = after.find(marker) else {
+            return;
+        };
+        let content = &after[..end];
+
+        if marker == "`" {
+            *input = Some(content.to_string());
+        } else if let Some(input) = input {
+            examples.push(Example {
+                input: input.clone(),
+                expected: content.to_string(),
+            });
+        }
+        rest = &after[end + marker.len()..];
+    }
+}
+
+/// splits tasks.md into the puzzles of each day. A day starts with "--- Day N: title ---", its
+/// second part with "--- Part Two ---".
+pub fn parse(markdown: &str) -> Vec<Puzzle> {
+    let mut puzzles: Vec<Puzzle> = vec![];
+    // the info string and the lines of the fenced block we are in
+    let mut fenced: Option<(&str, String)> = None;
+    // the closest example input above the current line
+    let mut input: Option<String> = None;
+
+    for line in markdown.lines() {
+        if fenced.is_none() {
+            if let Some((day, title)) = parse_day_header(line) {
+                puzzles.push(Puzzle {
+                    day,
+                    title,
+                    parts: vec![PuzzlePart::default()],
+                });
+                input = None;
+                continue;
+            }
+        }
+
+        let Some(puzzle) = puzzles.last_mut() else {
+            continue;
+        };
+
+        if fenced.is_none() && line == "--- Part Two ---" {
+            puzzle.parts.push(PuzzlePart::default());
+            continue;
+        }
+
+        let part = puzzle
+            .parts
+            .last_mut()
+            .expect("a puzzle starts with a part");
+        part.text.push_str(line);
+        part.text.push('\n');
+
+        match (fenced.take(), line.strip_prefix(FENCE)) {
+            (None, Some(info)) => fenced = Some((info.trim(), String::new())),
+            (Some(("answer", block)), Some(_)) => {
+                if let Some(input) = &input {
+                    part.examples.push(Example {
+                        input: input.clone(),
+                        expected: block.trim_end_matches('\n').to_string(),
+                    });
+                }
+            }
+            (Some((_, block)), Some(_)) => input = Some(block),
+            (Some((info, mut block)), None) => {
+                block.push_str(line);
+                block.push('\n');
+                fenced = Some((info, block));
+            }
+            (None, None) => match line.strip_prefix(ANSWER_PREFIX) {
+                Some(answer) => part.answer = Some(answer.trim_end_matches('.').to_string()),
+                None => scan_line(line, &mut input, &mut part.examples),
+            },
+        }
+    }
+
+    puzzles
+}
+
+/// the examples of one part of a day
+pub fn examples(markdown: &str, day: u8, part: u8) -> Vec<Example> {
+    parse(markdown)
+        .into_iter()
+        .find(|puzzle| puzzle.day == day)
+        .and_then(|puzzle| puzzle.parts.into_iter().nth(usize::from(part) - 1))
+        .map(|part| part.examples)
+        .unwrap_or_default()
+}
+
+/// solves every example of `S` written down in `markdown` with the default params and compares
+/// the answers. Panics on the first wrong answer and if there are no examples for the part.
+pub fn assert_examples<S: Solution>(markdown: &str) {
+    let examples = examples(markdown, S::DAY, S::PART);
+    assert!(
+        !examples.is_empty(),
+        "no examples for day {} part {} in tasks.md",
+        S::DAY,
+        S::PART
+    );
+
+    for example in examples {
+        let answer: Answer = S::solve(&prepare::prepare::<S>(&example.input), &Default::default())
+            .unwrap_or_else(|err| panic!("example input failed: {err}\n{}", example.input))
+            .into();
+        assert_eq!(
+            answer.to_string(),
+            example.expected,
+            "wrong answer for the example input\n{}",
+            example.input
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_days_and_answers() {
+        let markdown = "--- Day 1: Calorie Counting ---
+
+Find the Elf carrying the most Calories.
+
+Your puzzle answer was 73211.
+--- Part Two ---
+
+Find the top three Elves.
+
+Your puzzle answer was 213958.
+
+--- Day 2: Rock Paper Scissors ---
+
+What would your total score be?
+";
+        let puzzles = parse(markdown);
+
+        assert_eq!(puzzles.len(), 2);
+        assert_eq!(puzzles[0].day, 1);
+        assert_eq!(puzzles[0].title, "Calorie Counting");
+        assert_eq!(puzzles[0].parts[0].answer.as_deref(), Some("73211"));
+        assert_eq!(puzzles[0].parts[1].answer.as_deref(), Some("213958"));
+        assert!(puzzles[0].parts[1].text.contains("top three Elves"));
+        assert_eq!(puzzles[1].parts.len(), 1);
+        assert_eq!(puzzles[1].parts[0].answer, None);
+    }
+
+    #[test]
+    fn parses_examples() {
+        let markdown = "--- Day 6: Tuning Trouble ---
+
+For example:
+
+```
+mjqjpqmgbljsphdztnvjfqwrcgsmlb
+--- Part Two ---
+```
+
+Your subroutine should report the value **7**, not 4.
+
+    `bvwbjplbgvbhsrlpgdmjqwftvncz`: first marker after character **5**
+--- Part Two ---
+
+Now it is **19**.
+
+```answer
+#..
+.##
+```
+";
+        let puzzles = parse(markdown);
+
+        assert_eq!(puzzles[0].parts.len(), 2);
+        assert_eq!(
+            puzzles[0].parts[0].examples,
+            vec![
+                Example {
+                    input: "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n--- Part Two ---\n".to_string(),
+                    expected: "7".to_string(),
+                },
+                Example {
+                    input: "bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(),
+                    expected: "5".to_string(),
+                },
+            ]
+        );
+        assert_eq!(
+            examples(markdown, 6, 2)
+                .into_iter()
+                .map(|example| (example.input, example.expected))
+                .collect::<Vec<_>>(),
+            vec![
+                ("bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(), "19".to_string()),
+                (
+                    "bvwbjplbgvbhsrlpgdmjqwftvncz".to_string(),
+                    "#..\n.##".to_string()
+                ),
+            ]
+        );
+    }
+}
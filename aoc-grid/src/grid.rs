@@ -0,0 +1,277 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use aoc_core::Error;
+
+use crate::{Coord, Direction};
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T: Clone> Grid<T> {
+    /// a `width` x `height` grid with every cell set to `fill`
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            cells: vec![fill; width * height],
+            width,
+            height,
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// parses one row per line and one cell per char. `cell` returns None for chars that are no
+    /// cell, which fails with `expected` at their line and column. All rows need the same width.
+    pub fn parse(
+        text: &str,
+        expected: &str,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, Error> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (index, line) in text.lines().enumerate() {
+            let row_start = cells.len();
+            for (column, char) in line.chars().enumerate() {
+                let value = cell(char)
+                    .ok_or_else(|| Error::expected(index + 1, expected).at_column(column + 1))?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(Error::expected(
+                        index + 1,
+                        format!("a row of {width} cells like the first one"),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            cells,
+            width: width.unwrap_or(0),
+            height,
+        })
+    }
+
+    /// a grid from its rows, None if they are not all equally wide
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+
+        Some(Grid {
+            height: rows.len(),
+            width,
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        coord.x < self.width && coord.y < self.height
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.contains(coord)
+            .then(|| &self.cells[coord.y * self.width + coord.x])
+    }
+
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.contains(coord)
+            .then(|| &mut self.cells[coord.y * self.width + coord.x])
+    }
+
+    /// the next coord in `direction`, None if it leaves the grid
+    pub fn step(&self, coord: Coord, direction: Direction) -> Option<Coord> {
+        coord.step(direction).filter(|next| self.contains(*next))
+    }
+
+    /// the coords up, right, down and left of `coord` that are inside of the grid
+    pub fn neighbours4(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::CARDINAL
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction))
+    }
+
+    /// like [`Grid::neighbours4`], including the diagonal neighbours
+    pub fn neighbours8(&self, coord: Coord) -> impl Iterator<Item = Coord> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(coord, direction))
+    }
+
+    /// every coord from `coord` (excluded) in `direction` up to the edge of the grid
+    pub fn ray(&self, coord: Coord, direction: Direction) -> impl Iterator<Item = Coord> + '_ {
+        std::iter::successors(self.step(coord, direction), move |current| {
+            self.step(*current, direction)
+        })
+    }
+
+    /// every coord, row by row
+    pub fn coords(&self) -> impl Iterator<Item = Coord> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Coord::new(x, y)))
+    }
+
+    /// every cell with its coord, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.coords().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// a grid of the same size with every cell mapped
+    pub fn map<U>(&self, mut cell: impl FnMut(Coord, &T) -> U) -> Grid<U> {
+        Grid {
+            cells: self
+                .iter()
+                .map(|(coord, value)| cell(coord, value))
+                .collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl Grid<char> {
+    /// parses a grid where every char is a cell
+    pub fn from_chars(text: &str) -> Result<Self, Error> {
+        Grid::parse(text, "any char", Some)
+    }
+}
+
+impl<T> Index<Coord> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coord: Coord) -> &T {
+        self.get(coord).unwrap_or_else(|| {
+            panic!(
+                "{coord:?} is outside of the {}x{} grid",
+                self.width, self.height
+            )
+        })
+    }
+}
+
+impl<T> IndexMut<Coord> for Grid<T> {
+    fn index_mut(&mut self, coord: Coord) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(coord)
+            .unwrap_or_else(|| panic!("{coord:?} is outside of the {width}x{height} grid"))
+    }
+}
+
+/// one line per row, without a trailing newline
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(text: &str) -> Result<Grid<u32>, Error> {
+        Grid::parse(text, "a digit", |char| char.to_digit(10))
+    }
+
+    #[test]
+    fn parse_and_display() -> Result<(), Error> {
+        let grid = digits("123\n456\n")?;
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Coord::new(2, 1)], 6);
+        assert_eq!(grid.get(Coord::new(3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            digits("123\n4x6").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit"
+        );
+        assert_eq!(
+            digits("123\n45").unwrap_err().to_string(),
+            "line 2: expected a row of 3 cells like the first one"
+        );
+    }
+
+    #[test]
+    fn neighbours() -> Result<(), Error> {
+        let grid = digits("123\n456\n789")?;
+
+        let corner: Vec<u32> = grid
+            .neighbours4(Coord::new(0, 0))
+            .map(|coord| grid[coord])
+            .collect();
+        assert_eq!(corner, vec![2, 4]);
+
+        let center: Vec<u32> = grid
+            .neighbours8(Coord::new(1, 1))
+            .map(|coord| grid[coord])
+            .collect();
+        assert_eq!(center, vec![2, 3, 6, 9, 8, 7, 4, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn ray() -> Result<(), Error> {
+        let grid = digits("123\n456\n789")?;
+
+        let left: Vec<u32> = grid
+            .ray(Coord::new(2, 1), Direction::Left)
+            .map(|coord| grid[coord])
+            .collect();
+        assert_eq!(left, vec![5, 4]);
+        assert_eq!(grid.ray(Coord::new(0, 0), Direction::Up).count(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn new_and_map() {
+        let mut grid = Grid::new(2, 2, '.');
+        grid[Coord::new(1, 0)] = '#';
+
+        assert_eq!(grid.to_string(), ".#\n..");
+        assert_eq!(
+            grid.map(|coord, char| if *char == '#' { coord.x } else { 0 })
+                .to_string(),
+            "01\n00"
+        );
+    }
+}
@@ -0,0 +1,7 @@
+//! A rectangular 2D grid, as most puzzles with a map in their input need one.
+
+mod coord;
+mod grid;
+
+pub use coord::{Coord, Direction};
+pub use grid::Grid;
@@ -0,0 +1,87 @@
+use std::borrow::Cow;
+
+use nom::error::{ContextError, ErrorKind, FromExternalError};
+
+pub type IResult<'a, T> = nom::IResult<&'a str, T, ParseError<'a>>;
+
+/// What the parser expected at the `input` where it failed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError<'a> {
+    input: &'a str,
+    expected: Cow<'static, str>,
+}
+
+impl<'a> ParseError<'a> {
+    pub fn new(input: &'a str, expected: impl Into<Cow<'static, str>>) -> Self {
+        ParseError {
+            input,
+            expected: expected.into(),
+        }
+    }
+
+    /// the rest of the input, starting where the parser failed
+    pub fn input(&self) -> &'a str {
+        self.input
+    }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+/// describes the nom parsers that fail without knowing what they wanted
+fn describe(kind: ErrorKind) -> Cow<'static, str> {
+    match kind {
+        ErrorKind::Digit => "a number".into(),
+        ErrorKind::Alpha => "a letter".into(),
+        ErrorKind::AlphaNumeric => "a letter or digit".into(),
+        ErrorKind::Space | ErrorKind::MultiSpace => "whitespace".into(),
+        ErrorKind::CrLf => "a line break".into(),
+        ErrorKind::Eof => "the end of the input".into(),
+        kind => kind.description().to_string().into(),
+    }
+}
+
+impl<'a> nom::error::ParseError<&'a str> for ParseError<'a> {
+    fn from_error_kind(input: &'a str, kind: ErrorKind) -> Self {
+        ParseError::new(input, describe(kind))
+    }
+
+    /// keeps the innermost error, it knows best what was expected
+    fn append(_: &'a str, _: ErrorKind, other: Self) -> Self {
+        other
+    }
+
+    fn from_char(input: &'a str, char: char) -> Self {
+        ParseError::new(input, format!("`{char}`"))
+    }
+
+    /// keeps the alternative that got further, or lists both if they failed at the same place
+    fn or(self, other: Self) -> Self {
+        match self.input.len().cmp(&other.input.len()) {
+            std::cmp::Ordering::Less => self,
+            std::cmp::Ordering::Greater => other,
+            std::cmp::Ordering::Equal => ParseError::new(
+                self.input,
+                format!("{} or {}", self.expected, other.expected),
+            ),
+        }
+    }
+}
+
+/// a context names what was expected, unless the parser inside already got past its start
+impl<'a> ContextError<&'a str> for ParseError<'a> {
+    fn add_context(input: &'a str, context: &'static str, other: Self) -> Self {
+        if other.input.len() == input.len() {
+            ParseError::new(input, context)
+        } else {
+            other
+        }
+    }
+}
+
+impl<'a, E> FromExternalError<&'a str, E> for ParseError<'a> {
+    fn from_external_error(input: &'a str, kind: ErrorKind, _: E) -> Self {
+        nom::error::ParseError::from_error_kind(input, kind)
+    }
+}
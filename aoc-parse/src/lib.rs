@@ -0,0 +1,72 @@
+//! nom parsers for the shapes puzzle inputs come in: number lists, lines, blank line separated
+//! blocks, `key: value` fields, `x=2, y=18` coordinates and char grids.
+//!
+//! They fail with a [`ParseError`] that knows what was expected, [`parse_all`] turns it into an
+//! [`aoc_core::Error`] with the line and column of the input where parsing failed.
+
+mod error;
+mod parsers;
+
+pub use error::{IResult, ParseError};
+pub use parsers::{blocks, field, grid, lines, list, signed, tag, unsigned, xy};
+
+use aoc_core::Error;
+use nom::{character::complete::multispace0, Offset};
+
+/// runs `parser` over the whole input. Only whitespace may be left over.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> Result<T, Error> {
+    let (rest, value) = parser(input).map_err(|err| match err {
+        nom::Err::Error(err) | nom::Err::Failure(err) => error_at(input, &err),
+        nom::Err::Incomplete(_) => {
+            error_at(input, &ParseError::new(&input[input.len()..], "more input"))
+        }
+    })?;
+
+    let (rest, _) = multispace0::<_, ParseError>(rest).expect("multispace0 accepts any input");
+    if !rest.is_empty() {
+        return Err(error_at(
+            input,
+            &ParseError::new(rest, "the end of the line"),
+        ));
+    }
+
+    Ok(value)
+}
+
+/// the line and column of `err` inside of `input`
+fn error_at(input: &str, err: &ParseError) -> Error {
+    let consumed = &input[..input.offset(err.input())];
+    let line_start = consumed.rfind('\n').map_or(0, |index| index + 1);
+
+    Error::expected(consumed.matches('\n').count() + 1, err.expected())
+        .at_column(consumed[line_start..].chars().count() + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_positions() {
+        assert_eq!(
+            parse_all("1,2\n3,x", lines(list(",", unsigned::<u32>)))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: expected an unsigned number"
+        );
+        assert_eq!(
+            parse_all("1,2\n3 4\n", lines(list(",", unsigned::<u32>)))
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: expected the end of the line"
+        );
+    }
+
+    #[test]
+    fn trailing_whitespace() {
+        assert_eq!(parse_all("1\n2\n\n", lines(unsigned::<u8>)), Ok(vec![1, 2]));
+    }
+}
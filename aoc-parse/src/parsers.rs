@@ -0,0 +1,219 @@
+use std::str::FromStr;
+
+use aoc_grid::Grid;
+use nom::{
+    character::complete::{char, digit1, line_ending, not_line_ending, space0},
+    combinator::{map_res, opt, recognize},
+    error::context,
+    sequence::{delimited, pair, preceded, tuple},
+};
+
+use crate::{IResult, ParseError};
+
+/// like nom's `tag`, but the error names the missing text
+pub fn tag<'a>(text: &'static str) -> impl FnMut(&'a str) -> IResult<'a, &'a str> {
+    move |input| {
+        nom::bytes::complete::tag::<_, _, ParseError>(text)(input)
+            .map_err(|err| err.map(|_| ParseError::new(input, format!("`{text}`"))))
+    }
+}
+
+/// a number without a sign, like `42`
+pub fn unsigned<'a, T: FromStr>(input: &'a str) -> IResult<'a, T> {
+    context("an unsigned number", map_res(digit1, str::parse))(input)
+}
+
+/// a number with an optional `-`, like `-14`
+pub fn signed<'a, T: FromStr>(input: &'a str) -> IResult<'a, T> {
+    context(
+        "a number",
+        map_res(recognize(pair(opt(char('-')), digit1)), str::parse),
+    )(input)
+}
+
+/// at least one `item`, separated by `separator` and optional spaces around it, like `79, 98`
+pub fn list<'a, T>(
+    separator: &'static str,
+    mut item: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    let mut separator = delimited(space0, tag(separator), space0);
+
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+
+        while let Ok((rest, _)) = separator(input) {
+            let (rest, next) = item(rest)?;
+            items.push(next);
+            input = rest;
+        }
+        Ok((input, items))
+    }
+}
+
+/// `item` on every line. Once a line break is followed by more input, it has to be an item too,
+/// so the error points into the broken line.
+pub fn lines<'a, T>(
+    mut item: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |input| {
+        let (mut input, first) = item(input)?;
+        let mut items = vec![first];
+
+        loop {
+            match line_ending::<_, ParseError>(input) {
+                Ok((rest, _)) if !rest.trim().is_empty() => {
+                    let (rest, next) = item(rest)?;
+                    items.push(next);
+                    input = rest;
+                }
+                _ => return Ok((input, items)),
+            }
+        }
+    }
+}
+
+/// blocks of lines separated by an empty line, like the monkeys of 2022 day-11
+pub fn blocks<'a, T>(
+    mut block: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Vec<T>> {
+    move |input| {
+        let (mut input, first) = block(input)?;
+        let mut blocks = vec![first];
+
+        loop {
+            match pair(line_ending::<_, ParseError>, line_ending)(input) {
+                Ok((rest, _)) if !rest.trim().is_empty() => {
+                    let (rest, next) = block(rest)?;
+                    blocks.push(next);
+                    input = rest;
+                }
+                _ => return Ok((input, blocks)),
+            }
+        }
+    }
+}
+
+/// a `key: value` line, the key may be indented
+pub fn field<'a, T>(
+    key: &'static str,
+    value: impl FnMut(&'a str) -> IResult<'a, T>,
+) -> impl FnMut(&'a str) -> IResult<'a, T> {
+    preceded(tuple((space0, tag(key), tag(":"), space0)), value)
+}
+
+/// a coordinate like `x=2, y=18`
+pub fn xy<'a, T: FromStr>(input: &'a str) -> IResult<'a, (T, T)> {
+    let (input, (_, x, _, y)) = tuple((tag("x="), signed, tag(", y="), signed))(input)?;
+    Ok((input, (x, y)))
+}
+
+/// a grid with one cell per char. `cell` returns None for chars that are no cell, they fail
+/// with `expected`.
+pub fn grid<'a, T>(
+    expected: &'static str,
+    mut cell: impl FnMut(char) -> Option<T>,
+) -> impl FnMut(&'a str) -> IResult<'a, Grid<T>> {
+    move |input| {
+        let mut row = |line: &'a str| -> IResult<'a, Vec<T>> {
+            let (rest, text) = not_line_ending(line)?;
+            let cells = text
+                .char_indices()
+                .map(|(index, char)| cell(char).ok_or(index))
+                .collect::<Result<Vec<T>, usize>>()
+                .map_err(|index| nom::Err::Failure(ParseError::new(&line[index..], expected)))?;
+
+            if cells.is_empty() {
+                return Err(nom::Err::Error(ParseError::new(line, expected)));
+            }
+            Ok((rest, cells))
+        };
+
+        let (rest, rows) = lines(&mut row)(input)?;
+        let width = rows[0].len();
+        if let Some(ragged) = rows.iter().position(|row| row.len() != width) {
+            let line = input.lines().nth(ragged).unwrap_or_default();
+            return Err(nom::Err::Failure(ParseError::new(
+                line,
+                "a row as wide as the first one",
+            )));
+        }
+
+        let grid = Grid::from_rows(rows).expect("the rows have the same width");
+        Ok((rest, grid))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse_all;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(
+            parse_all("79, 98,-3", list(",", signed::<i32>)),
+            Ok(vec![79, 98, -3])
+        );
+        assert_eq!(
+            parse_all("-3", unsigned::<u32>).unwrap_err().to_string(),
+            "line 1, column 1: expected an unsigned number"
+        );
+    }
+
+    #[test]
+    fn blocks_of_fields() {
+        let input = "Test: 1\n  Size: 2\n\nTest: 3\n  Size: x\n";
+        let mut block = pair(
+            field("Test", unsigned::<u8>),
+            preceded(line_ending, field("Size", unsigned::<u8>)),
+        );
+
+        assert_eq!(
+            parse_all(
+                "Test: 1\n  Size: 2\n\nTest: 3\n  Size: 4",
+                blocks(&mut block)
+            ),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+        assert_eq!(
+            parse_all(input, blocks(&mut block))
+                .unwrap_err()
+                .to_string(),
+            "line 5, column 9: expected an unsigned number"
+        );
+        assert_eq!(
+            parse_all("Test: 1\n  Length: 2", block)
+                .unwrap_err()
+                .to_string(),
+            "line 2, column 3: expected `Size`"
+        );
+    }
+
+    #[test]
+    fn coordinates() {
+        assert_eq!(parse_all("x=2, y=-18", xy::<i64>), Ok((2, -18)));
+        assert_eq!(
+            parse_all("x=2 y=18", xy::<i64>).unwrap_err().to_string(),
+            "line 1, column 4: expected `, y=`"
+        );
+    }
+
+    #[test]
+    fn char_grids() {
+        let digits = |input| parse_all(input, grid("a digit", |char| char.to_digit(10)));
+
+        assert_eq!(
+            digits("12\n34\n").map(|grid| grid.to_string()),
+            Ok("12\n34".to_string())
+        );
+        assert_eq!(
+            digits("12\n3x").unwrap_err().to_string(),
+            "line 2, column 2: expected a digit"
+        );
+        assert_eq!(
+            digits("12\n345").unwrap_err().to_string(),
+            "line 2, column 1: expected a row as wide as the first one"
+        );
+    }
+}
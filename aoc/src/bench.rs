@@ -0,0 +1,314 @@
+use std::{collections::BTreeMap, fs, io, path::Path, time::Duration};
+
+use aoc_core::{log, DynSolution};
+
+use crate::{
+    isolate::{self, Outcome},
+    ledger::PuzzleKey,
+    solutions,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Timing {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Timing {
+    fn from_samples(mut samples: Vec<Duration>) -> Self {
+        samples.sort();
+        Timing {
+            min: samples[0],
+            median: samples[samples.len() / 2],
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+/// Solves `input` `runs` times after one warm up run that is not measured. The input is
+/// prepared once up front, so only solving it is timed. Every run is
+/// [isolated](isolate::run_prepared), the first one which does not solve the input ends the
+/// measurement.
+pub fn measure(
+    solution: &'static dyn DynSolution,
+    input: &str,
+    runs: usize,
+    timeout: Duration,
+) -> Result<Timing, Outcome> {
+    let input = solution.prepare(input);
+    let mut samples = vec![];
+    for run in 0..=runs.max(1) {
+        match isolate::run_prepared(solution, input.clone(), timeout) {
+            // the warm up run
+            Outcome::Solved { .. } if run == 0 => {}
+            Outcome::Solved { elapsed, .. } => samples.push(elapsed),
+            outcome => return Err(outcome),
+        }
+    }
+
+    Ok(Timing::from_samples(samples))
+}
+
+/// The timings of an earlier benchmark, saved as one `{year} {day} {part} {min} {median} {max}`
+/// per line with the durations in nanoseconds.
+#[derive(Debug, Default, PartialEq)]
+pub struct Baseline {
+    timings: BTreeMap<PuzzleKey, Timing>,
+}
+
+impl Baseline {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut baseline = Baseline::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let numbers: Vec<u64> = line
+                .split_whitespace()
+                .map(|field| field.parse())
+                .collect::<Result<_, _>>()
+                .map_err(|_| format!("line {}: expected a number", index + 1))?;
+            let [year, day, part, min, median, max] = numbers[..] else {
+                return Err(format!(
+                    "line {}: expected `{{year}} {{day}} {{part}} {{min}} {{median}} {{max}}`",
+                    index + 1
+                ));
+            };
+
+            baseline.timings.insert(
+                (year as u16, day as u8, part as u8),
+                Timing {
+                    min: Duration::from_nanos(min),
+                    median: Duration::from_nanos(median),
+                    max: Duration::from_nanos(max),
+                },
+            );
+        }
+
+        Ok(baseline)
+    }
+
+    /// loads the baseline from `path`. A missing file is an empty baseline.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut text =
+            String::from("# benchmark baseline in ns: {year} {day} {part} {min} {median} {max}\n");
+        for ((year, day, part), timing) in self.timings.iter() {
+            text += &format!(
+                "{year} {day} {part} {} {} {}\n",
+                timing.min.as_nanos(),
+                timing.median.as_nanos(),
+                timing.max.as_nanos()
+            );
+        }
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+        }
+        fs::write(path, text).map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, key: PuzzleKey) -> Option<Timing> {
+        self.timings.get(&key).copied()
+    }
+
+    pub fn insert(&mut self, key: PuzzleKey, timing: Timing) {
+        self.timings.insert(key, timing);
+    }
+}
+
+/// change of the median against the baseline in percent
+fn change(timing: Timing, baseline: Timing) -> f64 {
+    let baseline = baseline.median.as_secs_f64();
+    (timing.median.as_secs_f64() - baseline) / baseline * 100.0
+}
+
+pub struct BenchOptions {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub runs: usize,
+    /// a median this many percent slower than the baseline counts as a regression
+    pub threshold: f64,
+    pub save: bool,
+    pub timeout: Duration,
+}
+
+/// benchmarks every selected solution against its real input and prints a table of the
+/// timings, compared to the saved baseline
+pub fn bench(options: BenchOptions) -> Result<(), String> {
+    if cfg!(debug_assertions) {
+        println!("note: this is a debug build, use `cargo run --release` for meaningful timings");
+    }
+    if log::ENABLED {
+        println!("note: the solutions may log, build with `--features bench` to compile it out");
+    }
+
+    let baseline_path = solutions::bench_baseline_path();
+    let mut baseline = Baseline::load(&baseline_path)?;
+    let mut regressions = 0;
+
+    println!(
+        "{:<20} {:>12} {:>12} {:>12} {:>12} {:>8}",
+        "puzzle", "min", "median", "max", "baseline", "change"
+    );
+
+    for solution in solutions::select(options.year, options.day) {
+        let key = (solution.year(), solution.day(), solution.part());
+        let name = format!("{} day {:02} part {}", key.0, key.1, key.2);
+
+        let input = match solutions::read_input(solution, None) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{name:<20} ERROR {err}");
+                continue;
+            }
+        };
+        let timing = match measure(solution, &input, options.runs, options.timeout) {
+            Ok(timing) => timing,
+            Err(Outcome::Failed(err)) => {
+                println!("{name:<20} ERROR {err}");
+                continue;
+            }
+            Err(Outcome::Panicked(message)) => {
+                println!("{name:<20} PANIC {message}");
+                continue;
+            }
+            Err(Outcome::TimedOut(timeout)) => {
+                // the solution keeps running on its thread and would slow down every later one
+                println!("{name:<20} TIMEOUT after {timeout:?}");
+                println!("the timed out solution is still running, the remaining puzzles are not benchmarked");
+                break;
+            }
+            Err(Outcome::Solved { .. }) => unreachable!("measure only fails for unsolved runs"),
+        };
+
+        let (previous, change) = match baseline.get(key) {
+            Some(previous) => {
+                let change = change(timing, previous);
+                let marker = if change > options.threshold {
+                    regressions += 1;
+                    " REGRESSION"
+                } else {
+                    ""
+                };
+                (
+                    format!("{:?}", previous.median),
+                    format!("{change:+.1}%{marker}"),
+                )
+            }
+            None => ("-".to_string(), "-".to_string()),
+        };
+
+        println!(
+            "{name:<20} {:>12} {:>12} {:>12} {previous:>12} {change:>8}",
+            format!("{:?}", timing.min),
+            format!("{:?}", timing.median),
+            format!("{:?}", timing.max),
+        );
+
+        if options.save {
+            baseline.insert(key, timing);
+        }
+    }
+
+    if regressions > 0 {
+        println!(
+            "{regressions} solutions are more than {}% slower than the baseline",
+            options.threshold
+        );
+    }
+    if options.save {
+        baseline.save(&baseline_path)?;
+        println!("saved the baseline to {}", baseline_path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::{Error, Solution};
+
+    use super::*;
+
+    struct Unfinished;
+
+    impl Solution for Unfinished {
+        const YEAR: u16 = 2023;
+        const DAY: u8 = 3;
+        const PART: u8 = 2;
+
+        type Params = ();
+        type Answer = usize;
+
+        fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+            if input.trim() == "done" {
+                return Ok(1);
+            }
+            todo!()
+        }
+    }
+
+    #[test]
+    fn measures_isolated_runs() {
+        let timeout = Duration::from_secs(5);
+        let timing = measure(&Unfinished, "done", 3, timeout).unwrap();
+        assert!(timing.min <= timing.median && timing.median <= timing.max);
+
+        let Err(Outcome::Panicked(message)) = measure(&Unfinished, "input", 3, timeout) else {
+            panic!("expected a panic");
+        };
+        assert!(
+            message.starts_with("not yet implemented at aoc/src/bench.rs:"),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn timing_from_samples() {
+        let samples = [5, 1, 3, 2, 4].map(Duration::from_millis).to_vec();
+        assert_eq!(
+            Timing::from_samples(samples),
+            Timing {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(3),
+                max: Duration::from_millis(5),
+            }
+        );
+    }
+
+    #[test]
+    fn baseline_round_trip() {
+        let baseline = Baseline::parse("# comment\n2022 15 2 10 20 30\n").unwrap();
+        let timing = baseline.get((2022, 15, 2)).unwrap();
+
+        assert_eq!(timing.median, Duration::from_nanos(20));
+        assert_eq!(
+            change(
+                Timing {
+                    median: Duration::from_nanos(30),
+                    ..timing
+                },
+                timing
+            )
+            .round(),
+            50.0
+        );
+        assert_eq!(
+            Baseline::parse("2022 15 2 10 20"),
+            Err("line 1: expected `{year} {day} {part} {min} {median} {max}`".to_string())
+        );
+    }
+}
@@ -0,0 +1,112 @@
+use std::{
+    env, fs, io,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::input::INPUT_DIR_VAR;
+
+use crate::solutions;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The settings of the runner, saved as `key = value` lines in .aoc-config at the repository
+/// root. The file holds the session token, so it is not committed.
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    /// the `session` cookie of a logged in adventofcode.com account
+    pub session: Option<String>,
+    pub base_url: String,
+    /// downloaded inputs are cached as `{input_dir}/{year}/day-{DD}.txt`. Defaults to
+    /// $AOC_INPUT_DIR or inputs/ at the repository root.
+    pub input_dir: PathBuf,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            session: None,
+            base_url: DEFAULT_BASE_URL.to_string(),
+            input_dir: env::var_os(INPUT_DIR_VAR)
+                .map(PathBuf::from)
+                .unwrap_or_else(|| solutions::repo_root().join("inputs")),
+        }
+    }
+}
+
+impl Config {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut config = Config::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                return Err(format!(
+                    "line {}: expected `{{key}} = {{value}}`",
+                    index + 1
+                ));
+            };
+            let value = value.trim().to_string();
+
+            match key.trim() {
+                "session" => config.session = Some(value),
+                "base_url" => config.base_url = value.trim_end_matches('/').to_string(),
+                "input_dir" => config.input_dir = PathBuf::from(value),
+                key => return Err(format!("line {}: unknown key `{key}`", index + 1)),
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// loads the config from `path`. A missing file uses the defaults.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Config::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn session(&self) -> Result<&str, String> {
+        self.session.as_deref().ok_or_else(|| {
+            format!(
+                "there is no session token, add `session = ...` to {}",
+                solutions::config_path().display()
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_config() {
+        let config = Config::parse(
+            "# comment\nsession = 53616c7465\nbase_url = http://127.0.0.1:8080/\ninput_dir = /tmp/aoc\n",
+        )
+        .unwrap();
+
+        assert_eq!(config.session(), Ok("53616c7465"));
+        assert_eq!(config.base_url, "http://127.0.0.1:8080");
+        assert_eq!(config.input_dir, PathBuf::from("/tmp/aoc"));
+    }
+
+    #[test]
+    fn malformed_config() {
+        assert_eq!(
+            Config::parse("session 123"),
+            Err("line 1: expected `{key} = {value}`".to_string())
+        );
+        assert_eq!(
+            Config::parse("\ntoken = 123"),
+            Err("line 2: unknown key `token`".to_string())
+        );
+        assert!(Config::parse("").unwrap().session().is_err());
+    }
+}
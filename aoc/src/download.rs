@@ -0,0 +1,249 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use crate::config::Config;
+
+/// Talks to adventofcode.com, or whatever server `base_url` points at.
+pub struct Client {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(concat!("aoc-runner/", env!("CARGO_PKG_VERSION")))
+                .build(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, String> {
+        Ok(Client::new(&config.base_url, config.session()?))
+    }
+
+    /// GETs `path` below the base url
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.request("GET", &url).call();
+        Self::read(response, &url)
+    }
+
+    /// POSTs `form` to `path` below the base url
+    pub fn post(&self, path: &str, form: &[(&str, &str)]) -> Result<String, String> {
+        let url = format!("{}{path}", self.base_url);
+        let response = self.request("POST", &url).send_form(form);
+        Self::read(response, &url)
+    }
+
+    fn request(&self, method: &str, url: &str) -> ureq::Request {
+        self.agent
+            .request(method, url)
+            .set("Cookie", &format!("session={}", self.session))
+    }
+
+    fn read(response: Result<ureq::Response, ureq::Error>, url: &str) -> Result<String, String> {
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("could not read the answer of {url}: {err}")),
+            Err(ureq::Error::Status(404, _)) => Err(format!("{url} does not exist (yet)")),
+            Err(ureq::Error::Status(status @ (400 | 401 | 403 | 500), _)) => Err(format!(
+                "{url} answered {status}, the session token is probably wrong or expired"
+            )),
+            Err(ureq::Error::Status(status, _)) => Err(format!("{url} answered {status}")),
+            Err(ureq::Error::Transport(err)) => {
+                Err(format!("could not reach {url}: {}", err.kind()))
+            }
+        }
+    }
+}
+
+/// Downloaded inputs, one `{year}/day-{DD}.txt` per day. This is the layout
+/// [`aoc_core::input`] looks for in $AOC_INPUT_DIR.
+pub struct InputCache {
+    dir: PathBuf,
+}
+
+impl InputCache {
+    pub fn new(dir: &Path) -> Self {
+        InputCache {
+            dir: dir.to_path_buf(),
+        }
+    }
+
+    pub fn path(&self, year: u16, day: u8) -> PathBuf {
+        self.dir
+            .join(year.to_string())
+            .join(format!("day-{day:02}.txt"))
+    }
+
+    /// the path of the days input, which is only downloaded if it is not cached yet. The flag
+    /// tells if it was downloaded.
+    pub fn fetch(&self, client: &Client, year: u16, day: u8) -> Result<(PathBuf, bool), String> {
+        let path = self.path(year, day);
+        if path.is_file() {
+            return Ok((path, false));
+        }
+
+        let input = client.get(&format!("/{year}/day/{day}/input"))?;
+
+        let year_dir = path.parent().expect("the input lives in a year dir");
+        fs::create_dir_all(year_dir)
+            .map_err(|err| format!("could not create {}: {err}", year_dir.display()))?;
+        fs::write(&path, input)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+
+        Ok((path, true))
+    }
+}
+
+#[cfg(test)]
+pub mod stub {
+    //! A tiny HTTP server on localhost that answers every request with the same response.
+
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::{Arc, Mutex},
+        thread,
+    };
+
+    /// A request the stub received.
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub cookie: Option<String>,
+        pub body: String,
+    }
+
+    pub struct Stub {
+        pub base_url: String,
+        pub requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    /// answers every request with `status` and `body`
+    pub fn serve(status: u16, body: &'static str) -> Stub {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(vec![]));
+
+        let received = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    return;
+                };
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let mut parts = request_line.split_whitespace();
+                let method = parts.next().unwrap_or_default().to_string();
+                let path = parts.next().unwrap_or_default().to_string();
+
+                let mut cookie = None;
+                let mut content_length = 0;
+                loop {
+                    let mut header = String::new();
+                    reader.read_line(&mut header).unwrap();
+                    let header = header.trim_end();
+                    if header.is_empty() {
+                        break;
+                    }
+                    let (name, value) = header.split_once(": ").unwrap_or((header, ""));
+                    match name.to_ascii_lowercase().as_str() {
+                        "cookie" => cookie = Some(value.to_string()),
+                        "content-length" => content_length = value.parse().unwrap(),
+                        _ => {}
+                    }
+                }
+
+                let mut request_body = vec![0; content_length];
+                reader.read_exact(&mut request_body).unwrap();
+                received.lock().unwrap().push(Request {
+                    method,
+                    path,
+                    cookie,
+                    body: String::from_utf8(request_body).unwrap(),
+                });
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {status} STUB\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+            }
+        });
+
+        Stub { base_url, requests }
+    }
+
+    impl Stub {
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("aoc-download-{}-{name}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir
+    }
+
+    #[test]
+    fn downloads_once() {
+        let stub = stub::serve(200, "1-2,3-4\n");
+        let client = Client::new(&stub.base_url, "secret");
+        let dir = temp_dir("once");
+        let cache = InputCache::new(&dir);
+
+        let (path, downloaded) = cache.fetch(&client, 2022, 4).unwrap();
+        assert!(downloaded);
+        assert!(path.ends_with("2022/day-04.txt"));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1-2,3-4\n");
+
+        assert_eq!(cache.fetch(&client, 2022, 4), Ok((path, false)));
+
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2022/day/4/input");
+        assert_eq!(requests[0].body, "");
+        assert_eq!(requests[0].cookie.as_deref(), Some("session=secret"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn failed_downloads_are_not_cached() {
+        let stub = stub::serve(
+            400,
+            "Puzzle inputs differ by user.  Please log in to get your puzzle input.",
+        );
+        let client = Client::new(&stub.base_url, "expired");
+        let cache = InputCache::new(&temp_dir("failed"));
+
+        assert_eq!(
+            cache.fetch(&client, 2022, 4),
+            Err(format!(
+                "{}/2022/day/4/input answered 400, the session token is probably wrong or expired",
+                stub.base_url
+            ))
+        );
+        assert!(!cache.path(2022, 4).exists());
+    }
+}
@@ -0,0 +1,217 @@
+use std::{
+    any::Any,
+    cell::RefCell,
+    panic::{self, AssertUnwindSafe},
+    sync::{mpsc, Once},
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{Answer, DynSolution, Error};
+
+/// the name of the threads solutions run on, their panics are not printed by the default hook
+const THREAD_NAME: &str = "solution";
+
+/// how an isolated run of a solution ended
+#[derive(Debug, PartialEq)]
+pub enum Outcome {
+    Solved {
+        answer: Answer,
+        elapsed: Duration,
+    },
+    Failed(Error),
+    /// the panic message with the location of the panic
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+impl Outcome {
+    pub fn answer(self) -> Result<(Answer, Duration), String> {
+        match self {
+            Outcome::Solved { answer, elapsed } => Ok((answer, elapsed)),
+            Outcome::Failed(err) => Err(err.to_string()),
+            Outcome::Panicked(message) => Err(format!("the solution panicked: {message}")),
+            Outcome::TimedOut(timeout) => Err(format!(
+                "the solution did not finish within {}s",
+                timeout.as_secs_f64()
+            )),
+        }
+    }
+}
+
+thread_local! {
+    static PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "a panic without a message"
+    }
+}
+
+/// keeps the panic message of solution threads for [`run`] instead of printing it
+fn install_hook() {
+    static HOOK: Once = Once::new();
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if thread::current().name() != Some(THREAD_NAME) {
+                return default(info);
+            }
+            let mut message = payload_message(info.payload()).to_string();
+            if let Some(location) = info.location() {
+                message += &format!(" at {location}");
+            }
+            PANIC.with(|panic| *panic.borrow_mut() = Some(message));
+        }));
+    });
+}
+
+/// Runs `solution` on a thread of its own, so a panic or an endless loop does not take down
+/// the runner. A solution which times out is left running in the background, there is no way
+/// to stop a thread.
+pub fn run(solution: &'static dyn DynSolution, input: String, timeout: Duration) -> Outcome {
+    spawn(solution, input, timeout, |solution, input| {
+        solution.run(input)
+    })
+}
+
+/// like [`run`] for an input which is [prepared](DynSolution::prepare) already, only solving it
+/// is timed
+pub fn run_prepared(
+    solution: &'static dyn DynSolution,
+    input: String,
+    timeout: Duration,
+) -> Outcome {
+    spawn(solution, input, timeout, |solution, input| {
+        solution.solve_prepared(input)
+    })
+}
+
+fn spawn(
+    solution: &'static dyn DynSolution,
+    input: String,
+    timeout: Duration,
+    solve: fn(&dyn DynSolution, &str) -> Result<Answer, Error>,
+) -> Outcome {
+    install_hook();
+    let (sender, receiver) = mpsc::channel();
+
+    let spawned = thread::Builder::new()
+        .name(THREAD_NAME.to_string())
+        .spawn(move || {
+            let start = Instant::now();
+            let outcome = match panic::catch_unwind(AssertUnwindSafe(|| solve(solution, &input))) {
+                Ok(Ok(answer)) => Outcome::Solved {
+                    answer,
+                    elapsed: start.elapsed(),
+                },
+                Ok(Err(err)) => Outcome::Failed(err),
+                Err(payload) => Outcome::Panicked(
+                    PANIC
+                        .with(|panic| panic.borrow_mut().take())
+                        .unwrap_or_else(|| payload_message(payload.as_ref()).to_string()),
+                ),
+            };
+            // the runner stopped waiting if the solution timed out
+            let _ = sender.send(outcome);
+        });
+    if let Err(err) = spawned {
+        return Outcome::Panicked(format!("could not start a thread: {err}"));
+    }
+
+    receiver
+        .recv_timeout(timeout)
+        .unwrap_or(Outcome::TimedOut(timeout))
+}
+
+/// the `--timeout` of the runner in seconds, eg. `30` or `0.5`
+pub fn parse_timeout(seconds: &str) -> Result<Duration, String> {
+    seconds
+        .parse::<f64>()
+        .ok()
+        .and_then(|seconds| Duration::try_from_secs_f64(seconds).ok())
+        .filter(|timeout| !timeout.is_zero())
+        .ok_or_else(|| format!("expected a positive amount of seconds, got `{seconds}`"))
+}
+
+#[cfg(test)]
+mod tests {
+    use aoc_core::Solution;
+
+    use super::*;
+
+    macro_rules! solution {
+        ($name:ident, $input:ident => $solve:expr) => {
+            struct $name;
+
+            impl Solution for $name {
+                const YEAR: u16 = 2022;
+                const DAY: u8 = 1;
+                const PART: u8 = 1;
+
+                type Params = ();
+                type Answer = usize;
+
+                fn solve($input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+                    $solve
+                }
+            }
+        };
+    }
+
+    solution!(Counts, input => Ok(input.lines().count()));
+    solution!(Unwraps, input => Ok(input.trim().parse::<usize>().unwrap()));
+    solution!(Loops, _input => loop {
+        thread::sleep(Duration::from_millis(10));
+    });
+
+    const TIMEOUT: Duration = Duration::from_secs(5);
+
+    #[test]
+    fn solved() {
+        let Outcome::Solved { answer, .. } = run(&Counts, "1\n2\n".to_string(), TIMEOUT) else {
+            panic!("expected an answer");
+        };
+        assert_eq!(answer, Answer::from(2_usize));
+    }
+
+    #[test]
+    fn panics_are_caught() {
+        let Outcome::Panicked(message) = run(&Unwraps, "x".to_string(), TIMEOUT) else {
+            panic!("expected a panic");
+        };
+        assert!(
+            message.starts_with(
+                "called `Result::unwrap()` on an `Err` value: ParseIntError { kind: InvalidDigit } \
+                 at aoc/src/isolate.rs:"
+            ),
+            "{message}"
+        );
+    }
+
+    #[test]
+    fn timeouts() {
+        let timeout = Duration::from_millis(50);
+        assert_eq!(
+            run(&Loops, String::new(), timeout),
+            Outcome::TimedOut(timeout)
+        );
+        assert_eq!(
+            Outcome::TimedOut(timeout).answer(),
+            Err("the solution did not finish within 0.05s".to_string())
+        );
+    }
+
+    #[test]
+    fn timeout_arg() {
+        assert_eq!(parse_timeout("30"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_timeout("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_timeout("0").is_err());
+        assert!(parse_timeout("soon").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+use std::{collections::BTreeMap, fs, io, path::Path};
+
+use crate::ocr;
+
+/// (year, day, part)
+pub type PuzzleKey = (u16, u8, u8);
+
+/// The accepted answers of every puzzle, saved as one `{year} {day} {part} {answer}` per line.
+/// Days go from 1 to 25, parts are 1 or 2 and an answer is never empty.
+/// Answers the website rejected are kept as `wrong {year} {day} {part} {hint} {answer}`, so they
+/// are never submitted again.
+#[derive(Debug, Default, PartialEq)]
+pub struct Ledger {
+    answers: BTreeMap<PuzzleKey, String>,
+    wrong: BTreeMap<PuzzleKey, Vec<WrongAnswer>>,
+}
+
+/// what the website said about a wrong answer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WrongAnswer {
+    pub answer: String,
+    pub hint: Hint,
+}
+
+impl Hint {
+    fn as_str(self) -> &'static str {
+        match self {
+            Hint::TooHigh => "too_high",
+            Hint::TooLow => "too_low",
+            Hint::None => "wrong",
+        }
+    }
+
+    fn parse(text: &str) -> Option<Self> {
+        [Hint::TooHigh, Hint::TooLow, Hint::None]
+            .into_iter()
+            .find(|hint| hint.as_str() == text)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// there is no accepted answer for the puzzle yet
+    Unknown,
+}
+
+impl Ledger {
+    pub fn parse(text: &str) -> Result<Self, String> {
+        let mut ledger = Ledger::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(wrong) = line.strip_prefix("wrong ") {
+                let mut fields = wrong.splitn(5, ' ');
+                let key = parse_key(&mut fields);
+                let hint = fields.next().and_then(Hint::parse);
+                let (Some(key), Some(hint), Some(answer)) = (key, hint, parse_answer(&mut fields))
+                else {
+                    return Err(format!(
+                        "line {}: expected `wrong {{year}} {{day}} {{part}} {{hint}} {{answer}}`",
+                        index + 1
+                    ));
+                };
+
+                ledger.insert_wrong(key, answer.to_string(), hint);
+                continue;
+            }
+
+            let mut fields = line.splitn(4, ' ');
+            let (Some(key), Some(answer)) = (parse_key(&mut fields), parse_answer(&mut fields))
+            else {
+                return Err(format!(
+                    "line {}: expected `{{year}} {{day}} {{part}} {{answer}}`",
+                    index + 1
+                ));
+            };
+
+            ledger.insert(key, answer.to_string());
+        }
+
+        Ok(ledger)
+    }
+
+    /// loads the ledger from `path`. A missing file is an empty ledger.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Self::parse(&text).map_err(|err| format!("{}: {err}", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut text = String::from("# accepted puzzle answers: {year} {day} {part} {answer}\n");
+        for ((year, day, part), answer) in self.answers.iter() {
+            text += &format!("{year} {day} {part} {answer}\n");
+        }
+        for ((year, day, part), wrong) in self.wrong.iter() {
+            for WrongAnswer { answer, hint } in wrong {
+                text += &format!("wrong {year} {day} {part} {} {answer}\n", hint.as_str());
+            }
+        }
+
+        fs::write(path, text).map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, key: PuzzleKey) -> Option<&str> {
+        self.answers.get(&key).map(String::as_str)
+    }
+
+    /// records an accepted answer, returns the answer it replaced
+    pub fn insert(&mut self, key: PuzzleKey, answer: String) -> Option<String> {
+        self.answers.insert(key, answer)
+    }
+
+    /// records an answer the website rejected
+    pub fn insert_wrong(&mut self, key: PuzzleKey, answer: String, hint: Hint) {
+        let wrong = self.wrong.entry(key).or_default();
+        if !wrong.iter().any(|wrong| wrong.answer == answer) {
+            wrong.push(WrongAnswer { answer, hint });
+        }
+    }
+
+    /// the rejected answer, if `answer` was submitted before
+    pub fn get_wrong(&self, key: PuzzleKey, answer: &str) -> Option<&WrongAnswer> {
+        self.wrong
+            .get(&key)?
+            .iter()
+            .find(|wrong| wrong.answer == answer)
+    }
+
+    /// compares an answer against the accepted one. Answers drawn as letters (2022 day-10) are
+    /// read before they are compared.
+    pub fn check(&self, key: PuzzleKey, answer: &str) -> Verdict {
+        let Some(expected) = self.get(key) else {
+            return Verdict::Unknown;
+        };
+
+        let matches = answer == expected
+            || answer.contains('\n') && ocr::read_letters(answer).as_deref() == Some(expected);
+
+        if matches {
+            Verdict::Pass
+        } else {
+            Verdict::Fail {
+                expected: expected.to_string(),
+            }
+        }
+    }
+}
+
+/// a year, a day from 1 to 25 and a part of 1 or 2
+fn parse_key<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Option<PuzzleKey> {
+    let year = fields.next()?.parse().ok()?;
+    let day = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|day| (1..=25).contains(day))?;
+    let part = fields
+        .next()?
+        .parse()
+        .ok()
+        .filter(|part| (1..=2).contains(part))?;
+    Some((year, day, part))
+}
+
+/// the rest of the line, which must not be empty
+fn parse_answer<'a>(fields: &mut impl Iterator<Item = &'a str>) -> Option<&'a str> {
+    fields.next().filter(|answer| !answer.trim().is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_and_check() {
+        let ledger = Ledger::parse("# comment\n2022 1 1 73211\n2022 10 2 RZHFGJCB\n").unwrap();
+
+        assert_eq!(ledger.check((2022, 1, 1), "73211"), Verdict::Pass);
+        assert_eq!(
+            ledger.check((2022, 1, 1), "73212"),
+            Verdict::Fail {
+                expected: "73211".to_string()
+            }
+        );
+        assert_eq!(ledger.check((2022, 1, 2), "213958"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn wrong_answers() {
+        let mut ledger = Ledger::parse("2022 1 1 73211\nwrong 2022 1 2 too_low 1000\n").unwrap();
+        ledger.insert_wrong((2022, 1, 2), "A B".to_string(), Hint::None);
+
+        assert_eq!(
+            ledger.get_wrong((2022, 1, 2), "1000"),
+            Some(&WrongAnswer {
+                answer: "1000".to_string(),
+                hint: Hint::TooLow
+            })
+        );
+        assert_eq!(ledger.get_wrong((2022, 1, 1), "1000"), None);
+
+        let path = std::env::temp_dir().join(format!("aoc-ledger-{}.txt", std::process::id()));
+        ledger.save(&path).unwrap();
+        assert_eq!(Ledger::load(&path), Ok(ledger));
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(
+            Ledger::parse("wrong 2022 1 2 maybe 1000"),
+            Err("line 1: expected `wrong {year} {day} {part} {hint} {answer}`".to_string())
+        );
+    }
+
+    #[test]
+    fn malformed_line() {
+        assert_eq!(
+            Ledger::parse("2022 1 1 73211\n2022 one 1 5"),
+            Err("line 2: expected `{year} {day} {part} {answer}`".to_string())
+        );
+        // day 300 is no day 44 and an answer is needed
+        for line in [
+            "2022 300 1 5",
+            "2022 1 3 5",
+            "2022 0 1 5",
+            "2022 1 1",
+            "2022 1 1  ",
+        ] {
+            assert_eq!(
+                Ledger::parse(&format!("# answers\n{line}\n")),
+                Err("line 2: expected `{year} {day} {part} {answer}`".to_string()),
+                "{line}"
+            );
+        }
+        assert!(Ledger::parse("wrong 2022 26 1 too_low 5").is_err());
+        assert!(Ledger::parse("wrong 2022 1 1 too_low").is_err());
+    }
+}
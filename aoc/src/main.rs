@@ -0,0 +1,464 @@
+mod bench;
+mod config;
+mod download;
+mod isolate;
+mod ledger;
+mod ocr;
+mod run_all;
+mod scaffold;
+mod solutions;
+mod stress;
+mod submit;
+mod verify;
+mod watch;
+
+use std::{
+    fs,
+    path::PathBuf,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use aoc_core::{log, tasks, Value};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use config::Config;
+use download::{Client, InputCache};
+use ledger::Ledger;
+use scaffold::DayNames;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the advent of code solutions of every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// what the solutions log to stderr, eg. `debug` or `info,2022-14=trace`
+    #[arg(long, global = true)]
+    log: Option<log::Filter>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// solve a single part of a day and print the answer with the time it took
+    Run(RunArgs),
+    /// run every solution and compare the answers against the ledger of accepted answers
+    Verify(VerifyArgs),
+    /// run every solution on a pool of threads and print a calendar of the answers and timings
+    RunAll(RunAllArgs),
+    /// manage the ledger of accepted answers
+    #[command(subcommand)]
+    Ledger(LedgerCommand),
+    /// time every solution against its real input and compare it to the saved baseline
+    Bench(BenchArgs),
+    /// download the input of a day into the input cache, unless it is cached already
+    Download {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+    },
+    /// create the crate of a new day from the template of its year, template/2022 for days in the
+    /// root workspace and template/2023 for years with a workspace of their own
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// run a part and the tests of its day, and again whenever the sources or the input change
+    Watch {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// a path passed on to the binary and watched as well
+        #[arg(long)]
+        input: Option<String>,
+        /// milliseconds without a change before the day is run again
+        #[arg(long, default_value_t = 300)]
+        quiet: u64,
+    },
+    /// print a random valid input of a day, the same one for the same seed and size
+    Generate {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// the amount of the thing the day is about, like elves or moves
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// solve generated inputs of a day and report the seeds a part fails on
+    Stress {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long, default_value_t = 100)]
+        size: usize,
+        /// inputs to generate
+        #[arg(long, default_value_t = 20)]
+        count: u64,
+        /// the seed of the first input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// seconds a part may run before it is reported as TIMEOUT
+        #[arg(long, default_value = "60", value_parser = isolate::parse_timeout)]
+        timeout: Duration,
+    },
+    /// submit the answer of a part and record what the website says about it in the ledger.
+    /// Refuses to submit while the cooldown after a wrong answer runs
+    Submit {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// defaults to the answer of the solution for the days input
+        answer: Option<String>,
+    },
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// measured runs per solution, after one warm up run
+    #[arg(long, default_value_t = 10)]
+    runs: usize,
+    /// a median this many percent slower than the baseline is reported as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+    /// save the timings as the new baseline
+    #[arg(long)]
+    save: bool,
+    /// seconds a single run may take before the solution is reported as TIMEOUT
+    #[arg(long, default_value = "60", value_parser = isolate::parse_timeout)]
+    timeout: Duration,
+}
+
+#[derive(Subcommand)]
+enum LedgerCommand {
+    /// record the accepted answers written down in a years tasks.md
+    Seed {
+        #[arg(long)]
+        year: u16,
+        /// defaults to {year}/tasks.md
+        #[arg(long)]
+        tasks: Option<PathBuf>,
+    },
+    /// record the accepted answer of a single part
+    Record {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        answer: String,
+    },
+}
+
+#[derive(Args)]
+struct VerifyArgs {
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// seconds a solution may run before it is reported as TIMEOUT
+    #[arg(long, default_value = "60", value_parser = isolate::parse_timeout)]
+    timeout: Duration,
+}
+
+#[derive(Args)]
+struct RunAllArgs {
+    #[command(flatten)]
+    filter: FilterArgs,
+    /// solutions run at the same time, defaults to the number of cpus
+    #[arg(long)]
+    jobs: Option<usize>,
+    /// seconds a solution may run before it is reported as TIMEOUT
+    #[arg(long, default_value = "60", value_parser = isolate::parse_timeout)]
+    timeout: Duration,
+    /// start no more solutions after the first one failed
+    #[arg(long)]
+    fail_fast: bool,
+}
+
+/// limits a command to the solutions of a year or a single day
+#[derive(Args)]
+struct FilterArgs {
+    #[arg(long)]
+    year: Option<u16>,
+    #[arg(long)]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct RunArgs {
+    #[arg(long)]
+    year: u16,
+    #[arg(long)]
+    day: u8,
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+    /// a path or `-` for stdin. Defaults to the day in $AOC_INPUT_DIR or the input file inside
+    /// of the days crate
+    #[arg(long)]
+    input: Option<String>,
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// seconds the solution may run before it is given up on
+    #[arg(long, default_value = "60", value_parser = isolate::parse_timeout)]
+    timeout: Duration,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    /// one json object with the year, day, part, answer and elapsed_ns
+    Json,
+}
+
+fn run(args: RunArgs) -> Result<(), String> {
+    let solution = solutions::find(args.year, args.day, args.part).ok_or_else(|| {
+        format!(
+            "there is no solution for {} day {:02} part {}",
+            args.year, args.day, args.part
+        )
+    })?;
+    let input = solutions::read_input(solution, args.input.as_deref())?;
+
+    let (answer, elapsed) = isolate::run(solution, input, args.timeout).answer()?;
+
+    match args.format {
+        Format::Text => {
+            // grids (eg. the day-10 crt) start on their own line
+            let separator = match answer.value {
+                Value::Grid(_) => "\n",
+                _ => " ",
+            };
+            println!(
+                "{} day {:02} part {}:{separator}{answer}",
+                args.year, args.day, args.part
+            );
+            if let Some(explanation) = &answer.explanation {
+                println!("{explanation}");
+            }
+            println!("took {elapsed:?}");
+        }
+        Format::Json => println!(
+            r#"{{"year": {}, "day": {}, "part": {}, "answer": {}, "elapsed_ns": {}}}"#,
+            args.year,
+            args.day,
+            args.part,
+            answer.to_json(),
+            elapsed.as_nanos()
+        ),
+    }
+
+    Ok(())
+}
+
+fn ledger(command: LedgerCommand) -> Result<(), String> {
+    let path = solutions::ledger_path();
+    let mut ledger = Ledger::load(&path)?;
+
+    match command {
+        LedgerCommand::Seed { year, tasks } => {
+            let tasks_path = tasks.unwrap_or_else(|| {
+                solutions::repo_root()
+                    .join(year.to_string())
+                    .join("tasks.md")
+            });
+            let markdown = fs::read_to_string(&tasks_path)
+                .map_err(|err| format!("could not read {}: {err}", tasks_path.display()))?;
+
+            let mut recorded = 0;
+            for puzzle in tasks::parse(&markdown) {
+                for (index, part) in puzzle.parts.into_iter().enumerate() {
+                    if let Some(answer) = part.answer {
+                        ledger.insert((year, puzzle.day, index as u8 + 1), answer);
+                        recorded += 1;
+                    }
+                }
+            }
+            println!("recorded {recorded} answers from {}", tasks_path.display());
+        }
+        LedgerCommand::Record {
+            year,
+            day,
+            part,
+            answer,
+        } => {
+            if let Some(previous) = ledger.insert((year, day, part), answer) {
+                println!("replaced the previous answer {previous}");
+            }
+        }
+    }
+
+    ledger.save(&path)
+}
+
+fn download(year: u16, day: u8) -> Result<(), String> {
+    let config = Config::load(&solutions::config_path())?;
+    let cache = InputCache::new(&config.input_dir);
+
+    match cache.fetch(&Client::from_config(&config)?, year, day)? {
+        (path, true) => println!("downloaded {year} day {day:02} to {}", path.display()),
+        (path, false) => println!("{year} day {day:02} is cached in {}", path.display()),
+    }
+    Ok(())
+}
+
+fn new_day(year: u16, day: u8) -> Result<(), String> {
+    let year_dir = solutions::repo_root().join(year.to_string());
+    let own_workspace = year_dir.join("rust").join("Cargo.toml").exists();
+    let names = DayNames::new(year, day, own_workspace);
+    // the examples of the puzzle text prefill the tests, if the text was saved already
+    let markdown = fs::read_to_string(year_dir.join("tasks.md")).ok();
+
+    let created = scaffold::create_day(
+        &scaffold::template_dir(
+            &solutions::repo_root().join("template"),
+            year,
+            own_workspace,
+        ),
+        &solutions::day_dir(year, day),
+        year,
+        day,
+        &names,
+        markdown.as_deref(),
+    )?;
+    for path in created {
+        println!("created {}", path.display());
+    }
+    println!(
+        "add `{}` to aoc/Cargo.toml and its Part1 and Part2 to aoc/src/solutions.rs to run it with `aoc run`",
+        names.package
+    );
+
+    Ok(())
+}
+
+fn submit(key: ledger::PuzzleKey, answer: Option<String>) -> Result<(), String> {
+    let (year, day, part) = key;
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let solution = solutions::find(year, day, part).ok_or_else(|| {
+                format!("there is no solution for {year} day {day:02} part {part}")
+            })?;
+            let answer = solution
+                .run(&solutions::read_input(solution, None)?)
+                .map_err(|err| err.to_string())?
+                .to_string();
+
+            // letters drawn by the solution are submitted as text
+            if answer.contains('\n') {
+                ocr::read_letters(&answer)
+                    .ok_or_else(|| format!("could not read the letters of\n{answer}"))?
+            } else {
+                answer
+            }
+        }
+    };
+
+    let config = Config::load(&solutions::config_path())?;
+    let ledger_path = solutions::ledger_path();
+    let mut ledger = Ledger::load(&ledger_path)?;
+    let cooldown_path = solutions::cooldown_path();
+    let mut cooldown = submit::Cooldown::load(&cooldown_path)?;
+
+    let outcome = submit::submit(
+        &Client::from_config(&config)?,
+        &mut ledger,
+        &mut cooldown,
+        key,
+        &answer,
+        SystemTime::now(),
+    );
+
+    ledger.save(&ledger_path)?;
+    cooldown.save(&cooldown_path)?;
+    println!("{year} day {day:02} part {part}: {answer} is {}", outcome?);
+    Ok(())
+}
+
+fn main() -> Result<(), String> {
+    let cli = Cli::parse();
+    if let Some(filter) = cli.log {
+        log::set_filter(filter);
+    }
+
+    match cli.command {
+        Command::Run(args) => run(args),
+        Command::Verify(args) => {
+            let ledger = Ledger::load(&solutions::ledger_path())?;
+            verify::verify(&ledger, args.filter.year, args.filter.day, args.timeout)
+        }
+        Command::RunAll(args) => run_all::run_all(run_all::RunAllOptions {
+            year: args.filter.year,
+            day: args.filter.day,
+            jobs: args
+                .jobs
+                .or_else(|| thread::available_parallelism().ok().map(usize::from))
+                .unwrap_or(1),
+            timeout: args.timeout,
+            fail_fast: args.fail_fast,
+        }),
+        Command::Ledger(command) => ledger(command),
+        Command::Bench(args) => bench::bench(bench::BenchOptions {
+            year: args.filter.year,
+            day: args.filter.day,
+            runs: args.runs,
+            threshold: args.threshold,
+            save: args.save,
+            timeout: args.timeout,
+        }),
+        Command::Download { year, day } => download(year, day),
+        Command::New { year, day } => new_day(year, day),
+        Command::Watch {
+            year,
+            day,
+            part,
+            input,
+            quiet,
+        } => watch::watch(watch::WatchOptions {
+            year,
+            day,
+            part,
+            input,
+            quiet: Duration::from_millis(quiet),
+        }),
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => stress::generate(year, day, size, seed).map(|input| print!("{input}")),
+        Command::Stress {
+            year,
+            day,
+            size,
+            count,
+            seed,
+            timeout,
+        } => stress::stress(stress::StressOptions {
+            year,
+            day,
+            size,
+            count,
+            seed,
+            timeout,
+        }),
+        Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+        } => submit((year, day, part), answer),
+    }
+}
@@ -0,0 +1,68 @@
+/// The 4x6 letters some puzzles (eg. 2022 day-10) draw instead of answering with text.
+const LETTERS: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// reads the letters of a 6 rows high picture, where every letter is 4 pixels wide and followed
+/// by one empty column. Returns None if the picture contains an unknown letter.
+pub fn read_letters(picture: &str) -> Option<String> {
+    let rows: Vec<&str> = picture.lines().collect();
+    if rows.len() != 6 {
+        return None;
+    }
+
+    let width = rows.iter().map(|row| row.len()).max()?;
+    (0..width)
+        .step_by(5)
+        .map(|start| {
+            let glyph: Vec<&str> = rows
+                .iter()
+                .map(|row| row.get(start..start + 4).unwrap_or(""))
+                .collect();
+
+            LETTERS
+                .iter()
+                .find(|(_, letter)| letter[..] == glyph[..])
+                .map(|(char, _)| *char)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_crt_letters() {
+        let picture = concat!(
+            "###..####.#..#.####..##....##..##..###..\n",
+            "#..#....#.#..#.#....#..#....#.#..#.#..#.\n",
+            "#..#...#..####.###..#.......#.#....###..\n",
+            "###...#...#..#.#....#.##....#.#....#..#.\n",
+            "#.#..#....#..#.#....#..#.#..#.#..#.#..#.\n",
+            "#..#.####.#..#.#.....###..##...##..###..",
+        );
+        assert_eq!(read_letters(picture).as_deref(), Some("RZHFGJCB"));
+    }
+
+    #[test]
+    fn unknown_letters() {
+        assert_eq!(read_letters("#\n#\n#\n#\n#\n#"), None);
+        assert_eq!(read_letters("not a picture"), None);
+    }
+}
@@ -0,0 +1,321 @@
+use std::{
+    collections::BTreeMap,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use aoc_core::{DynSolution, Value};
+
+use crate::{
+    isolate::{self, Outcome},
+    ledger::{Ledger, PuzzleKey, Verdict},
+    ocr, solutions,
+};
+
+/// how a solution did in a run of all of them
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Pass,
+    Fail {
+        expected: String,
+    },
+    /// solved, but there is no accepted answer to compare with
+    Unknown,
+    Error(String),
+    Panic(String),
+    Timeout,
+    /// not started, an earlier solution failed
+    Skipped,
+}
+
+impl Status {
+    fn label(&self) -> &'static str {
+        match self {
+            Status::Pass => "PASS",
+            Status::Fail { .. } => "FAIL",
+            Status::Unknown => "UNKNOWN",
+            Status::Error(_) => "ERROR",
+            Status::Panic(_) => "PANIC",
+            Status::Timeout => "TIMEOUT",
+            Status::Skipped => "SKIPPED",
+        }
+    }
+
+    fn failed(&self) -> bool {
+        matches!(
+            self,
+            Status::Fail { .. } | Status::Error(_) | Status::Panic(_) | Status::Timeout
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub key: PuzzleKey,
+    pub status: Status,
+    pub answer: Option<String>,
+    pub elapsed: Option<Duration>,
+}
+
+/// Calls `f` for every item on `jobs` threads and returns the results in the order of the
+/// items. Once `stop` is true for a result no more items are started, the items which were
+/// not started are `None`.
+pub fn parallel<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+    stop: impl Fn(&R) -> bool + Sync,
+) -> Vec<Option<R>> {
+    let next = AtomicUsize::new(0);
+    let stopped = AtomicBool::new(false);
+    let results = Mutex::new((0..items.len()).map(|_| None).collect::<Vec<_>>());
+
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, items.len().max(1)) {
+            scope.spawn(|| {
+                while !stopped.load(Ordering::Relaxed) {
+                    let index = next.fetch_add(1, Ordering::Relaxed);
+                    let Some(item) = items.get(index) else {
+                        break;
+                    };
+
+                    let result = f(item);
+                    if stop(&result) {
+                        stopped.store(true, Ordering::Relaxed);
+                    }
+                    results.lock().unwrap_or_else(|err| err.into_inner())[index] = Some(result);
+                }
+            });
+        }
+    });
+
+    results.into_inner().unwrap_or_else(|err| err.into_inner())
+}
+
+/// the answer as it fits in a table cell, drawn letters are read first
+fn short_answer(answer: &aoc_core::Answer) -> String {
+    let text = match &answer.value {
+        Value::Grid(picture) => ocr::read_letters(picture).unwrap_or_else(|| "(picture)".into()),
+        _ => answer.to_string(),
+    };
+    if text.chars().count() > 14 {
+        format!("{}…", text.chars().take(13).collect::<String>())
+    } else {
+        text
+    }
+}
+
+fn report(solution: &'static dyn DynSolution, ledger: &Ledger, timeout: Duration) -> Report {
+    let key = (solution.year(), solution.day(), solution.part());
+    let report = |status, answer, elapsed| Report {
+        key,
+        status,
+        answer,
+        elapsed,
+    };
+
+    let input = match solutions::read_input(solution, None) {
+        Ok(input) => input,
+        Err(err) => return report(Status::Error(err), None, None),
+    };
+
+    match isolate::run(solution, input, timeout) {
+        Outcome::Solved { answer, elapsed } => {
+            let status = match ledger.check(key, &answer.to_string()) {
+                Verdict::Pass => Status::Pass,
+                Verdict::Fail { expected } => Status::Fail { expected },
+                Verdict::Unknown => Status::Unknown,
+            };
+            report(status, Some(short_answer(&answer)), Some(elapsed))
+        }
+        Outcome::Failed(err) => report(Status::Error(err.to_string()), None, None),
+        Outcome::Panicked(message) => report(Status::Panic(message), None, None),
+        Outcome::TimedOut(timeout) => report(Status::Timeout, None, Some(timeout)),
+    }
+}
+
+fn cell(report: Option<&Report>) -> String {
+    let Some(report) = report else {
+        return format!("{:<8} {:<14} {:>10}", "-", "", "");
+    };
+    format!(
+        "{:<8} {:<14} {:>10}",
+        report.status.label(),
+        report.answer.as_deref().unwrap_or(""),
+        report
+            .elapsed
+            .map(|elapsed| format!("{elapsed:.1?}"))
+            .unwrap_or_default()
+    )
+}
+
+/// A calendar of every year with a row per day up to the last solved one and a column per
+/// part, followed by the failures and the totals.
+pub fn summary(reports: &[Report], wall_time: Duration) -> String {
+    let by_key: BTreeMap<PuzzleKey, &Report> =
+        reports.iter().map(|report| (report.key, report)).collect();
+    let mut years: BTreeMap<u16, u8> = BTreeMap::new();
+    for (year, day, _) in by_key.keys() {
+        let last_day = years.entry(*year).or_default();
+        *last_day = (*last_day).max(*day);
+    }
+
+    let mut lines = vec![];
+    for (year, last_day) in years {
+        lines.push(format!("{year:<8} {:<37}part 2", "part 1"));
+        for day in 1..=last_day {
+            let part1 = cell(by_key.get(&(year, day, 1)).copied());
+            let part2 = cell(by_key.get(&(year, day, 2)).copied());
+            lines.push(
+                format!("day {day:02}   {part1}   {part2}")
+                    .trim_end()
+                    .to_string(),
+            );
+        }
+        lines.push(String::new());
+    }
+
+    for report in reports {
+        let (year, day, part) = report.key;
+        let details = match &report.status {
+            Status::Fail { expected } => format!("expected {expected:?}"),
+            Status::Error(message) | Status::Panic(message) => message.clone(),
+            _ => continue,
+        };
+        lines.push(format!(
+            "{year} day {day:02} part {part}  {}  {details}",
+            report.status.label()
+        ));
+    }
+
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for report in reports {
+        *counts.entry(report.status.label()).or_default() += 1;
+    }
+    let counts = counts
+        .into_iter()
+        .map(|(label, count)| format!("{count} {label}"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let cpu_time: Duration = reports.iter().filter_map(|report| report.elapsed).sum();
+    lines.push(format!(
+        "total    {} solutions in {wall_time:.2?} ({cpu_time:.2?} solving): {counts}",
+        reports.len()
+    ));
+
+    lines.join("\n")
+}
+
+pub struct RunAllOptions {
+    pub year: Option<u16>,
+    pub day: Option<u8>,
+    pub jobs: usize,
+    pub timeout: Duration,
+    /// no more solutions are started after the first failure
+    pub fail_fast: bool,
+}
+
+/// runs every selected solution on a pool of threads and prints the summary. Fails if any
+/// solution failed.
+pub fn run_all(options: RunAllOptions) -> Result<(), String> {
+    let ledger = Ledger::load(&solutions::ledger_path())?;
+    let selected: Vec<_> = solutions::select(options.year, options.day).collect();
+
+    let start = Instant::now();
+    let reports: Vec<Report> = parallel(
+        &selected,
+        options.jobs,
+        |solution| report(*solution, &ledger, options.timeout),
+        |report| options.fail_fast && report.status.failed(),
+    )
+    .into_iter()
+    .zip(&selected)
+    .map(|(report, solution)| {
+        report.unwrap_or(Report {
+            key: (solution.year(), solution.day(), solution.part()),
+            status: Status::Skipped,
+            answer: None,
+            elapsed: None,
+        })
+    })
+    .collect();
+
+    println!("{}", summary(&reports, start.elapsed()));
+
+    let failed = reports
+        .iter()
+        .filter(|report| report.status.failed())
+        .count();
+    if failed > 0 {
+        return Err(format!("{failed} solutions failed"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parallel_keeps_the_order() {
+        let items: Vec<u32> = (1..=20).collect();
+        let results = parallel(&items, 4, |item| item * 2, |_| false);
+        assert_eq!(
+            results,
+            items.iter().map(|item| Some(item * 2)).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn parallel_stops_starting_items() {
+        let items: Vec<u32> = (1..=20).collect();
+        let results = parallel(&items, 1, |item| *item, |item| *item == 3);
+        assert_eq!(&results[..4], &[Some(1), Some(2), Some(3), None]);
+        assert!(results[3..].iter().all(Option::is_none));
+    }
+
+    #[test]
+    fn calendar() {
+        let reports = [
+            Report {
+                key: (2022, 1, 1),
+                status: Status::Pass,
+                answer: Some("69883".to_string()),
+                elapsed: Some(Duration::from_micros(1200)),
+            },
+            Report {
+                key: (2022, 1, 2),
+                status: Status::Fail {
+                    expected: "207576".to_string(),
+                },
+                answer: Some("1".to_string()),
+                elapsed: Some(Duration::from_micros(1400)),
+            },
+            Report {
+                key: (2022, 3, 1),
+                status: Status::Panic("boom at day-03/src/lib.rs:4:5".to_string()),
+                answer: None,
+                elapsed: None,
+            },
+        ];
+
+        assert_eq!(
+            summary(&reports, Duration::from_millis(2)),
+            [
+                "2022     part 1                               part 2",
+                "day 01   PASS     69883               1.2ms   FAIL     1                   1.4ms",
+                "day 02   -                                    -",
+                "day 03   PANIC                                -",
+                "",
+                "2022 day 01 part 2  FAIL  expected \"207576\"",
+                "2022 day 03 part 1  PANIC  boom at day-03/src/lib.rs:4:5",
+                "total    3 solutions in 2.00ms (2.60ms solving): 1 FAIL, 1 PANIC, 1 PASS",
+            ]
+            .join("\n")
+        );
+    }
+}
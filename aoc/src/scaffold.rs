@@ -0,0 +1,279 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use aoc_core::tasks;
+
+/// The names a new day gets in its years workspace. Years with their own workspace
+/// (2023/rust/Cargo.toml) share the dependencies of it, the others are members of the root
+/// workspace and depend on the shared crates by path.
+#[derive(Debug, PartialEq)]
+pub struct DayNames {
+    pub package: String,
+    pub crate_name: String,
+    aoc_core: String,
+}
+
+impl DayNames {
+    pub fn new(year: u16, day: u8, own_workspace: bool) -> Self {
+        if own_workspace {
+            DayNames {
+                package: format!("day-{day:02}"),
+                crate_name: format!("day_{day:02}"),
+                aoc_core: "{ workspace = true }".to_string(),
+            }
+        } else {
+            DayNames {
+                package: format!("aoc{year}-day-{day:02}"),
+                crate_name: format!("day_{day:02}"),
+                aoc_core: r#"{ path = "../../../aoc-core" }"#.to_string(),
+            }
+        }
+    }
+}
+
+/// The template of a years layout in `templates`. 2022 keeps its days in the root workspace with
+/// `src/bin/part-1.rs` and `process_input1`, 2023 has a workspace of its own with `src/part1.rs`
+/// and `part1::solve`. A year without a template of its own takes the one of its layout.
+pub fn template_dir(templates: &Path, year: u16, own_workspace: bool) -> PathBuf {
+    let dir = templates.join(year.to_string());
+    if dir.is_dir() {
+        return dir;
+    }
+    templates.join(if own_workspace { "2023" } else { "2022" })
+}
+
+/// the first example of a part from the puzzle text as rust string literals, empty ones if
+/// there is none
+fn example_literals(markdown: Option<&str>, day: u8, part: u8) -> (String, String) {
+    let example =
+        markdown.and_then(|markdown| tasks::examples(markdown, day, part).into_iter().next());
+
+    match example {
+        Some(example) => (
+            format!("{:?}", example.input),
+            format!("{:?}", example.expected),
+        ),
+        None => (r#""""#.to_string(), r#""""#.to_string()),
+    }
+}
+
+/// an `#[ignore]` for the example test of a part without examples in the puzzle text, which would
+/// fail until the examples are marked in tasks.md
+fn ignore_without_examples(markdown: Option<&str>, day: u8, part: u8) -> String {
+    let has_examples =
+        markdown.is_some_and(|markdown| !tasks::examples(markdown, day, part).is_empty());
+    if has_examples {
+        String::new()
+    } else {
+        format!("\n    #[ignore = \"no examples for day {day} part {part} in tasks.md\"]")
+    }
+}
+
+/// replaces the `{{name}}` placeholders of a template file
+fn render(template: &str, values: &[(&str, String)]) -> String {
+    values
+        .iter()
+        .fold(template.to_string(), |text, (name, value)| {
+            text.replace(&format!("{{{{{name}}}}}"), value)
+        })
+}
+
+/// copies every file of `template_dir` into `day_dir`, rendered for the day. `markdown` is the
+/// years tasks.md, if there is one. Returns the created files.
+pub fn create_day(
+    template_dir: &Path,
+    day_dir: &Path,
+    year: u16,
+    day: u8,
+    names: &DayNames,
+    markdown: Option<&str>,
+) -> Result<Vec<PathBuf>, String> {
+    if day_dir.exists() {
+        return Err(format!(
+            "{} exists already, it is not overwritten",
+            day_dir.display()
+        ));
+    }
+
+    let (example_input_1, example_answer_1) = example_literals(markdown, day, 1);
+    let (example_input_2, example_answer_2) = example_literals(markdown, day, 2);
+    let values = [
+        ("package", names.package.clone()),
+        ("crate_name", names.crate_name.clone()),
+        ("aoc_core", names.aoc_core.clone()),
+        ("year", year.to_string()),
+        ("day", day.to_string()),
+        ("dd", format!("{day:02}")),
+        ("example_input_1", example_input_1),
+        ("example_answer_1", example_answer_1),
+        ("example_input_2", example_input_2),
+        ("example_answer_2", example_answer_2),
+        ("ignore_1", ignore_without_examples(markdown, day, 1)),
+        ("ignore_2", ignore_without_examples(markdown, day, 2)),
+    ];
+
+    let mut created = vec![];
+    let mut dirs = vec![PathBuf::new()];
+    while let Some(dir) = dirs.pop() {
+        let entries = fs::read_dir(template_dir.join(&dir))
+            .map_err(|err| format!("could not read {}: {err}", template_dir.display()))?;
+
+        for entry in entries {
+            let entry = entry.map_err(|err| err.to_string())?;
+            let relative = dir.join(entry.file_name());
+            if entry.path().is_dir() {
+                dirs.push(relative);
+                continue;
+            }
+
+            let template = fs::read_to_string(entry.path())
+                .map_err(|err| format!("could not read {}: {err}", entry.path().display()))?;
+            let path = day_dir.join(&relative);
+            fs::create_dir_all(path.parent().expect("the file lives in the day dir"))
+                .map_err(|err| format!("could not create {}: {err}", day_dir.display()))?;
+            fs::write(&path, render(&template, &values))
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+            created.push(path);
+        }
+    }
+
+    created.sort();
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+    use crate::solutions;
+
+    #[test]
+    fn names_per_workspace() {
+        assert_eq!(DayNames::new(2023, 4, true).package, "day-04");
+        assert_eq!(DayNames::new(2022, 16, false).package, "aoc2022-day-16");
+        assert_eq!(DayNames::new(2022, 16, false).crate_name, "day_16");
+    }
+
+    #[test]
+    fn templates_per_layout() {
+        let templates = solutions::repo_root().join("template");
+        assert_eq!(
+            template_dir(&templates, 2022, false),
+            templates.join("2022")
+        );
+        assert_eq!(template_dir(&templates, 2023, true), templates.join("2023"));
+        assert_eq!(template_dir(&templates, 2024, true), templates.join("2023"));
+        assert_eq!(
+            template_dir(&templates, 2021, false),
+            templates.join("2022")
+        );
+    }
+
+    #[test]
+    fn creates_a_2022_day() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-2022-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let day_dir = dir.join("day-16");
+        // only the first part has an example yet
+        let markdown =
+            "--- Day 16: Proboscidea Volcanium ---\n\n```\nValve AA\n```\n\nIt is **1651**.\n";
+
+        let created = create_day(
+            &template_dir(&solutions::repo_root().join("template"), 2022, false),
+            &day_dir,
+            2022,
+            16,
+            &DayNames::new(2022, 16, false),
+            Some(markdown),
+        )
+        .unwrap();
+        assert_eq!(
+            created,
+            [
+                "Cargo.toml",
+                "input.txt",
+                "src/bin/part-1.rs",
+                "src/bin/part-2.rs",
+                "src/lib.rs"
+            ]
+            .map(|file| day_dir.join(file))
+        );
+
+        let manifest = fs::read_to_string(day_dir.join("Cargo.toml")).unwrap();
+        assert!(manifest.contains(r#"name = "aoc2022-day-16""#));
+        assert!(manifest.contains(r#"name = "day_16""#));
+        assert!(
+            manifest.contains("[[bin]]\nname = \"day-16-part-2\"\npath = \"src/bin/part-2.rs\"")
+        );
+        assert!(manifest.contains(r#"aoc-core = { path = "../../../aoc-core" }"#));
+
+        let lib = fs::read_to_string(day_dir.join("src/lib.rs")).unwrap();
+        assert!(lib.contains("pub fn process_input1(_file: String) -> Result<String, Error> {"));
+        assert!(lib.contains(r#"todo!("day 16 - part 2")"#));
+        assert!(lib.contains("const YEAR: u16 = 2022;"));
+        assert!(lib.contains("const DAY: u8 = 16;"));
+        assert!(lib.contains(r#"include_str!("../../../tasks.md")"#));
+        assert!(lib.contains("    #[test]\n    fn part_1() {"));
+        assert!(lib.contains(
+            "    #[test]\n    #[ignore = \"no examples for day 16 part 2 in tasks.md\"]\n    fn part_2() {"
+        ));
+
+        let bin = fs::read_to_string(day_dir.join("src/bin/part-1.rs")).unwrap();
+        assert!(bin.contains("use day_16::{process_input1, Part1};"));
+        assert!(bin.contains("Output part 01"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn creates_a_day_from_the_template() {
+        let dir = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let day_dir = dir.join("day-07");
+        let markdown = "--- Day 7: Camel Cards ---\n\n```\n32T3K 765\n```\n\nTotal **6440**.\n";
+
+        let created = create_day(
+            &template_dir(&solutions::repo_root().join("template"), 2023, true),
+            &day_dir,
+            2023,
+            7,
+            &DayNames::new(2023, 7, true),
+            Some(markdown),
+        )
+        .unwrap();
+
+        assert!(created.contains(&day_dir.join("input1.txt")));
+        assert_eq!(fs::read_to_string(day_dir.join("input2.txt")).unwrap(), "");
+
+        let part1 = fs::read_to_string(day_dir.join("src/part1.rs")).unwrap();
+        assert!(part1.contains(r#"todo!("day 07 - part 1")"#));
+        assert!(part1.contains("const DAY: u8 = 7;"));
+        assert!(part1.contains(r#"let input = "32T3K 765\n";"#));
+        assert!(part1.contains(r#"let expected = "6440";"#));
+
+        let part2 = fs::read_to_string(day_dir.join("src/part2.rs")).unwrap();
+        assert!(part2.contains(r#"let input = "";"#));
+
+        let bin = fs::read_to_string(day_dir.join("src/bin/part2.rs")).unwrap();
+        assert!(bin.contains("use day_07::part2::{solve, Part2};"));
+        assert!(fs::read_to_string(day_dir.join("Cargo.toml"))
+            .unwrap()
+            .contains(r#"name = "day-07""#));
+
+        let error = create_day(
+            &template_dir(&solutions::repo_root().join("template"), 2023, true),
+            &day_dir,
+            2023,
+            7,
+            &DayNames::new(2023, 7, true),
+            None,
+        )
+        .unwrap_err();
+        assert!(error.ends_with("exists already, it is not overwritten"));
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,196 @@
+use std::path::{Path, PathBuf};
+
+use aoc_core::{generate::Rng, input, DynSolution};
+
+use crate::config::Config;
+
+/// the repository root, one level above this crate
+pub fn repo_root() -> &'static Path {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("the aoc crate lives inside the repository")
+}
+
+/// the crate of a day, eg. 2022/rust/day-09
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    repo_root()
+        .join(year.to_string())
+        .join("rust")
+        .join(format!("day-{day:02}"))
+}
+
+/// reads the input of a solution, `arg` is an explicit path or `-` for stdin. Without one the
+/// input is looked up like the days binaries do (see [`aoc_core::input`]), with the download
+/// cache of the config as the input dir.
+pub fn read_input(solution: &dyn DynSolution, arg: Option<&str>) -> Result<String, String> {
+    let config = Config::load(&config_path())?;
+    let candidates = input::candidates(
+        Some(&config.input_dir),
+        &day_dir(solution.year(), solution.day()),
+        solution.year(),
+        solution.day(),
+        solution.part(),
+    );
+
+    input::resolve(arg, &candidates)
+        .and_then(|source| source.read())
+        .map_err(|err| err.to_string())
+}
+
+/// the settings of the runner, see [`crate::config::Config`]
+pub fn config_path() -> PathBuf {
+    repo_root().join(".aoc-config")
+}
+
+/// the ledger of accepted answers, see [`crate::ledger::Ledger`]
+pub fn ledger_path() -> PathBuf {
+    repo_root().join("answers.txt")
+}
+
+/// the timings saved by `aoc bench --save`. They depend on the machine, so they stay in target/
+pub fn bench_baseline_path() -> PathBuf {
+    repo_root().join("target").join("bench-baseline.txt")
+}
+
+/// the time before which `aoc submit` sends no answer, kept next to the bench baseline
+pub fn cooldown_path() -> PathBuf {
+    repo_root().join("target").join("submit-cooldown.txt")
+}
+
+/// every solution of every year, ordered by year, day and part
+pub static SOLUTIONS: &[&dyn DynSolution] = &[
+    &aoc2022_day_01::Part1,
+    &aoc2022_day_01::Part2,
+    &aoc2022_day_02::Part1,
+    &aoc2022_day_02::Part2,
+    &aoc2022_day_03::Part1,
+    &aoc2022_day_03::Part2,
+    &aoc2022_day_04::Part1,
+    &aoc2022_day_04::Part2,
+    &aoc2022_day_05::Part1,
+    &aoc2022_day_05::Part2,
+    &aoc2022_day_06::Part1,
+    &aoc2022_day_06::Part2,
+    &aoc2022_day_07::Part1,
+    &aoc2022_day_07::Part2,
+    &aoc2022_day_08::Part1,
+    &aoc2022_day_08::Part2,
+    &aoc2022_day_09::Part1,
+    &aoc2022_day_09::Part2,
+    &aoc2022_day_10::Part1,
+    &aoc2022_day_10::Part2,
+    &aoc2022_day_11::Part1,
+    &aoc2022_day_11::Part2,
+    &aoc2022_day_12::Part1,
+    &aoc2022_day_12::Part2,
+    &aoc2022_day_13::Part1,
+    &aoc2022_day_13::Part2,
+    &aoc2022_day_14::Part1,
+    &aoc2022_day_14::Part2,
+    &aoc2022_day_15::Part1,
+    &aoc2022_day_15::Part2,
+    &aoc2023_day_01::part1::Part1,
+    &aoc2023_day_01::part2::Part2,
+    &aoc2023_day_02::part1::Part1,
+    &aoc2023_day_02::part2::Part2,
+    &aoc2023_day_03::part1::Part1,
+    &aoc2023_day_03::part2::Part2,
+];
+
+/// makes up a valid input of a day, see [`aoc_core::generate`]
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// the days with an input generator, ordered by year and day
+pub static GENERATORS: &[(u16, u8, Generator)] = &[
+    (2022, 1, aoc2022_day_01::generate),
+    (2022, 5, aoc2022_day_05::generate),
+    (2022, 7, aoc2022_day_07::generate),
+    (2022, 9, aoc2022_day_09::generate),
+    (2022, 11, aoc2022_day_11::generate),
+    (2022, 12, aoc2022_day_12::generate),
+    (2022, 15, aoc2022_day_15::generate),
+];
+
+pub fn find_generator(year: u16, day: u8) -> Result<Generator, String> {
+    GENERATORS
+        .iter()
+        .find(|(generator_year, generator_day, _)| (*generator_year, *generator_day) == (year, day))
+        .map(|(_, _, generator)| *generator)
+        .ok_or_else(|| format!("there is no input generator for {year} day {day:02}"))
+}
+
+/// the solutions of a year or a single day, every solution if neither is given
+pub fn select(
+    year: Option<u16>,
+    day: Option<u8>,
+) -> impl Iterator<Item = &'static dyn DynSolution> {
+    SOLUTIONS.iter().copied().filter(move |solution| {
+        year.is_none_or(|year| solution.year() == year)
+            && day.is_none_or(|day| solution.day() == day)
+    })
+}
+
+pub fn find(year: u16, day: u8, part: u8) -> Option<&'static dyn DynSolution> {
+    SOLUTIONS
+        .iter()
+        .find(|solution| (solution.year(), solution.day(), solution.part()) == (year, day, part))
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_dirs() {
+        assert!(day_dir(2022, 9).ends_with("2022/rust/day-09"));
+        assert!(day_dir(2023, 1).ends_with("2023/rust/day-01"));
+    }
+
+    #[test]
+    fn finds_the_day() {
+        let solution = find(2022, 6, 1).unwrap();
+        assert_eq!(
+            solution
+                .run("mjqjpqmgbljsphdztnvjfqwrcgsmlb")
+                .map(|answer| answer.to_string()),
+            Ok("7".to_string())
+        );
+        assert!(find(2022, 25, 1).is_none());
+    }
+
+    #[test]
+    fn select_by_year_and_day() {
+        assert_eq!(select(Some(2022), Some(4)).count(), 2);
+        assert!(select(Some(2023), None).all(|solution| solution.year() == 2023));
+        assert_eq!(select(None, None).count(), SOLUTIONS.len());
+    }
+
+    #[test]
+    fn solutions_are_ordered() {
+        let keys: Vec<_> = SOLUTIONS
+            .iter()
+            .map(|solution| (solution.year(), solution.day(), solution.part()))
+            .collect();
+        let mut sorted = keys.clone();
+        sorted.sort();
+        sorted.dedup();
+        assert_eq!(keys, sorted);
+    }
+
+    #[test]
+    fn generated_inputs_are_solved() {
+        for (year, day, generator) in GENERATORS {
+            let input = generator(&mut Rng::new(1), 10);
+            for part in [1, 2] {
+                let solution = find(*year, *day, part).unwrap();
+                assert!(
+                    solution.run(&input).is_ok(),
+                    "{year} day {day:02} part {part}"
+                );
+            }
+        }
+        assert!(find_generator(2022, 15).is_ok());
+        assert!(find_generator(2022, 2).is_err());
+    }
+}
@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use aoc_core::generate::Rng;
+
+use crate::{
+    isolate::{self, Outcome},
+    solutions,
+};
+
+pub struct StressOptions {
+    pub year: u16,
+    pub day: u8,
+    pub size: usize,
+    /// inputs generated, with the seeds `seed..seed + count`
+    pub count: u64,
+    pub seed: u64,
+    pub timeout: Duration,
+}
+
+/// the input of `seed`, as `aoc generate` prints it
+pub fn generate(year: u16, day: u8, size: usize, seed: u64) -> Result<String, String> {
+    let generator = solutions::find_generator(year, day)?;
+    Ok(generator(&mut Rng::new(seed), size))
+}
+
+/// Solves both parts of a day for generated inputs and prints every seed a part fails on. The
+/// inputs are valid, so an error, a panic or a timeout is a bug of the solver. The failing input
+/// is printed by `aoc generate` with the same seed and size.
+pub fn stress(options: StressOptions) -> Result<(), String> {
+    let parts: Vec<_> = solutions::select(Some(options.year), Some(options.day)).collect();
+    let mut failures = 0;
+
+    for seed in options.seed..options.seed + options.count {
+        let input = generate(options.year, options.day, options.size, seed)?;
+        for solution in &parts {
+            let problem = match isolate::run(*solution, input.clone(), options.timeout) {
+                Outcome::Solved { .. } => continue,
+                Outcome::Failed(err) => format!("ERROR  {err}"),
+                Outcome::Panicked(message) => format!("PANIC  {message}"),
+                Outcome::TimedOut(timeout) => format!("TIMEOUT  after {timeout:.1?}"),
+            };
+            failures += 1;
+            println!("seed {seed} part {}  {problem}", solution.part());
+        }
+    }
+
+    println!(
+        "{} {} day {:02} inputs of size {}, {failures} failed part(s)",
+        options.count, options.year, options.day, options.size
+    );
+    if failures > 0 {
+        return Err(format!(
+            "reproduce an input with `aoc generate --year {} --day {} --size {} --seed <seed>`",
+            options.year, options.day, options.size
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,377 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::Path,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use crate::{
+    download::Client,
+    ledger::{Hint, Ledger, PuzzleKey},
+};
+
+/// what the website said about a submitted answer
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// an answer was submitted too recently, the next one is accepted after the wait
+    RateLimited(Duration),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "that's the right answer"),
+            Outcome::TooHigh => write!(f, "wrong, the answer is too high"),
+            Outcome::TooLow => write!(f, "wrong, the answer is too low"),
+            Outcome::Wrong => write!(f, "wrong"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "answered too recently, wait {}s", wait.as_secs())
+            }
+        }
+    }
+}
+
+/// reads the outcome from the page the website answers a submission with
+pub fn parse_outcome(page: &str) -> Result<Outcome, String> {
+    if page.contains("That's the right answer") {
+        Ok(Outcome::Correct)
+    } else if page.contains("That's not the right answer") {
+        if page.contains("your answer is too high") {
+            Ok(Outcome::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Ok(Outcome::TooLow)
+        } else {
+            Ok(Outcome::Wrong)
+        }
+    } else if page.contains("You gave an answer too recently") {
+        Ok(Outcome::RateLimited(
+            parse_wait(page).unwrap_or(Duration::from_secs(60)),
+        ))
+    } else if page.contains("You don't seem to be solving the right level") {
+        Err("the part is locked or solved already".to_string())
+    } else {
+        Err("the website answered with an unknown page".to_string())
+    }
+}
+
+/// the time the page asks to wait before the next answer, eg. "You have 1m 5s left to wait" or
+/// "please wait 5 minutes before trying again"
+pub fn parse_wait(page: &str) -> Option<Duration> {
+    if let Some(start) = page.find("You have ") {
+        let left = &page[start + "You have ".len()..];
+        let left = &left[..left.find(" left to wait")?];
+
+        return left
+            .split_whitespace()
+            .map(
+                |amount| match amount.split_at(amount.len().checked_sub(1)?) {
+                    (minutes, "m") => minutes.parse::<u64>().ok().map(|minutes| minutes * 60),
+                    (seconds, "s") => seconds.parse::<u64>().ok(),
+                    _ => None,
+                },
+            )
+            .sum::<Option<u64>>()
+            .map(Duration::from_secs);
+    }
+
+    let wait = page.split("wait ").nth(1)?;
+    let (amount, unit) = wait.split_once(' ')?;
+    let minutes = match amount {
+        "one" => 1,
+        amount => amount.parse().ok()?,
+    };
+    unit.starts_with("minute")
+        .then(|| Duration::from_secs(minutes * 60))
+}
+
+/// The point in time before which no answer is submitted, saved as seconds since the unix epoch.
+#[derive(Debug, Default, PartialEq)]
+pub struct Cooldown {
+    until: u64,
+}
+
+impl Cooldown {
+    /// loads the cooldown from `path`. A missing file is no cooldown.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => text
+                .trim()
+                .parse()
+                .map(|until| Cooldown { until })
+                .map_err(|_| format!("{}: expected the seconds since 1970", path.display())),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Cooldown::default()),
+            Err(err) => Err(format!("could not read {}: {err}", path.display())),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)
+                .map_err(|err| format!("could not create {}: {err}", dir.display()))?;
+        }
+        fs::write(path, format!("{}\n", self.until))
+            .map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    /// the time left to wait at `now`
+    pub fn remaining(&self, now: SystemTime) -> Option<Duration> {
+        let now = seconds_since_epoch(now);
+        (self.until > now).then(|| Duration::from_secs(self.until - now))
+    }
+
+    pub fn start(&mut self, now: SystemTime, wait: Duration) {
+        self.until = seconds_since_epoch(now) + wait.as_secs();
+    }
+}
+
+fn seconds_since_epoch(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map_or(0, |since| since.as_secs())
+}
+
+/// Submits `answer`, unless the part is solved, the answer was rejected before or the cooldown
+/// is not over. Nothing waits for the cooldown, the submission is refused with the time left.
+/// The outcome is recorded in the ledger and the cooldown. The ledger keeps an answer per line,
+/// so answers spanning lines (like a picture of letters) are refused as well.
+pub fn submit(
+    client: &Client,
+    ledger: &mut Ledger,
+    cooldown: &mut Cooldown,
+    key: PuzzleKey,
+    answer: &str,
+    now: SystemTime,
+) -> Result<Outcome, String> {
+    let (year, day, part) = key;
+
+    if answer.contains(['\n', '\r']) {
+        return Err(
+            "the answer spans several lines, submit the letters it shows instead".to_string(),
+        );
+    }
+    if let Some(accepted) = ledger.get(key) {
+        return Err(format!(
+            "the part is solved already, the answer was {accepted}"
+        ));
+    }
+    if let Some(wrong) = ledger.get_wrong(key, answer) {
+        return Err(format!(
+            "{answer} was submitted before and was {}",
+            match wrong.hint {
+                Hint::TooHigh => "too high",
+                Hint::TooLow => "too low",
+                Hint::None => "wrong",
+            }
+        ));
+    }
+    if let Some(wait) = cooldown.remaining(now) {
+        return Err(format!(
+            "wait {}s before submitting another answer",
+            wait.as_secs()
+        ));
+    }
+
+    let page = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let outcome = parse_outcome(&page)?;
+
+    let hint = match outcome {
+        Outcome::Correct => {
+            ledger.insert(key, answer.to_string());
+            return Ok(outcome);
+        }
+        Outcome::RateLimited(wait) => {
+            cooldown.start(now, wait);
+            return Ok(outcome);
+        }
+        Outcome::TooHigh => Hint::TooHigh,
+        Outcome::TooLow => Hint::TooLow,
+        Outcome::Wrong => Hint::None,
+    };
+    ledger.insert_wrong(key, answer.to_string(), hint);
+    cooldown.start(now, parse_wait(&page).unwrap_or(Duration::from_secs(60)));
+
+    Ok(outcome)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::download::stub;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        If you're stuck, make sure you're using the full input data. Please wait one minute \
+        before trying again. [<a href=\"/2022/day/1\">Return to Day 1</a>]</p></article>";
+
+    #[test]
+    fn parses_outcomes() {
+        assert_eq!(
+            parse_outcome("<p>That's the right answer! You are one gold star closer.</p>"),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(parse_outcome(TOO_HIGH), Ok(Outcome::TooHigh));
+        assert_eq!(
+            parse_outcome("That's not the right answer; your answer is too low."),
+            Ok(Outcome::TooLow)
+        );
+        assert_eq!(
+            parse_outcome(
+                "That's not the right answer. Please wait 5 minutes before trying again."
+            ),
+            Ok(Outcome::Wrong)
+        );
+        assert_eq!(
+            parse_outcome(
+                "You gave an answer too recently; you have to wait after submitting an answer \
+                 before trying again.  You have 1m 5s left to wait."
+            ),
+            Ok(Outcome::RateLimited(Duration::from_secs(65)))
+        );
+        assert!(parse_outcome("<html>maintenance</html>").is_err());
+    }
+
+    #[test]
+    fn parses_waits() {
+        assert_eq!(parse_wait(TOO_HIGH), Some(Duration::from_secs(60)));
+        assert_eq!(
+            parse_wait("please wait 5 minutes before trying again"),
+            Some(Duration::from_secs(300))
+        );
+        assert_eq!(
+            parse_wait("You have 37s left to wait."),
+            Some(Duration::from_secs(37))
+        );
+        assert_eq!(parse_wait("That's the right answer!"), None);
+    }
+
+    #[test]
+    fn remembers_wrong_answers() {
+        let stub = stub::serve(200, TOO_HIGH);
+        let client = Client::new(&stub.base_url, "secret");
+        let mut ledger = Ledger::default();
+        let mut cooldown = Cooldown::default();
+        let now = UNIX_EPOCH + Duration::from_secs(1_000);
+
+        assert_eq!(
+            submit(
+                &client,
+                &mut ledger,
+                &mut cooldown,
+                (2022, 1, 2),
+                "500",
+                now
+            ),
+            Ok(Outcome::TooHigh)
+        );
+        let requests = stub.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path, "/2022/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=500");
+        assert_eq!(
+            ledger.get_wrong((2022, 1, 2), "500").unwrap().hint,
+            Hint::TooHigh
+        );
+
+        assert_eq!(
+            submit(
+                &client,
+                &mut ledger,
+                &mut cooldown,
+                (2022, 1, 2),
+                "400",
+                now
+            ),
+            Err("wait 60s before submitting another answer".to_string())
+        );
+        let later = now + Duration::from_secs(60);
+        assert_eq!(
+            submit(
+                &client,
+                &mut ledger,
+                &mut cooldown,
+                (2022, 1, 2),
+                "500",
+                later
+            ),
+            Err("500 was submitted before and was too high".to_string())
+        );
+        assert_eq!(stub.requests().len(), 1);
+    }
+
+    #[test]
+    fn records_the_right_answer() {
+        let stub = stub::serve(200, "<p>That's the right answer!</p>");
+        let client = Client::new(&stub.base_url, "secret");
+        let mut ledger = Ledger::default();
+        let mut cooldown = Cooldown::default();
+
+        assert_eq!(
+            submit(
+                &client,
+                &mut ledger,
+                &mut cooldown,
+                (2022, 1, 1),
+                "73211",
+                SystemTime::now()
+            ),
+            Ok(Outcome::Correct)
+        );
+        assert_eq!(ledger.get((2022, 1, 1)), Some("73211"));
+        assert_eq!(stub.requests().len(), 1);
+        assert_eq!(cooldown, Cooldown::default());
+        assert!(submit(
+            &client,
+            &mut ledger,
+            &mut cooldown,
+            (2022, 1, 1),
+            "1",
+            SystemTime::now()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn refuses_answers_spanning_lines() {
+        let stub = stub::serve(200, "<p>That's the right answer!</p>");
+        let client = Client::new(&stub.base_url, "secret");
+        let mut ledger = Ledger::default();
+
+        assert_eq!(
+            submit(
+                &client,
+                &mut ledger,
+                &mut Cooldown::default(),
+                (2022, 10, 2),
+                "##..\n#.#.",
+                SystemTime::now()
+            ),
+            Err("the answer spans several lines, submit the letters it shows instead".to_string())
+        );
+        assert_eq!(stub.requests().len(), 0);
+        assert_eq!(ledger, Ledger::default());
+    }
+
+    #[test]
+    fn rate_limits_start_the_cooldown() {
+        let stub = stub::serve(
+            200,
+            "You gave an answer too recently. You have 42s left to wait.",
+        );
+        let client = Client::new(&stub.base_url, "secret");
+        let mut ledger = Ledger::default();
+        let mut cooldown = Cooldown::default();
+        let now = SystemTime::now();
+
+        assert_eq!(
+            submit(&client, &mut ledger, &mut cooldown, (2022, 1, 1), "1", now),
+            Ok(Outcome::RateLimited(Duration::from_secs(42)))
+        );
+        assert_eq!(ledger.get_wrong((2022, 1, 1), "1"), None);
+        assert_eq!(cooldown.remaining(now), Some(Duration::from_secs(42)));
+    }
+}
@@ -0,0 +1,67 @@
+use std::time::Duration;
+
+use crate::{
+    isolate::{self, Outcome},
+    ledger::{Ledger, Verdict},
+    solutions,
+};
+
+/// runs every solution matching the filter and prints PASS, FAIL or UNKNOWN for each of them.
+/// A solution which panics or runs longer than `timeout` is reported as PANIC or TIMEOUT and
+/// the others still run. Fails if any answer differs from the ledger or could not be calculated.
+pub fn verify(
+    ledger: &Ledger,
+    year: Option<u16>,
+    day: Option<u8>,
+    timeout: Duration,
+) -> Result<(), String> {
+    let mut failed = 0;
+
+    for solution in solutions::select(year, day) {
+        let key = (solution.year(), solution.day(), solution.part());
+        let name = format!("{} day {:02} part {}", key.0, key.1, key.2);
+
+        let outcome = match solutions::read_input(solution, None) {
+            Ok(input) => isolate::run(solution, input, timeout),
+            Err(err) => {
+                failed += 1;
+                println!("{name}  ERROR    {err}");
+                continue;
+            }
+        };
+        let answer = match outcome {
+            Outcome::Solved { answer, .. } => Ok(answer.to_string()),
+            Outcome::Failed(err) => Err(err.to_string()),
+            Outcome::Panicked(message) => {
+                failed += 1;
+                println!("{name}  PANIC    {message}");
+                continue;
+            }
+            Outcome::TimedOut(timeout) => {
+                failed += 1;
+                println!("{name}  TIMEOUT  after {}s", timeout.as_secs_f64());
+                continue;
+            }
+        };
+
+        match answer.map(|answer| (ledger.check(key, &answer), answer)) {
+            Ok((Verdict::Pass, _)) => println!("{name}  PASS"),
+            Ok((Verdict::Fail { expected }, answer)) => {
+                failed += 1;
+                println!("{name}  FAIL     expected {expected:?}, got {answer:?}");
+            }
+            Ok((Verdict::Unknown, answer)) => println!("{name}  UNKNOWN  got {answer:?}"),
+            Err(err) => {
+                failed += 1;
+                println!("{name}  ERROR    {err}");
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(format!(
+            "{failed} solutions did not give the accepted answer"
+        ));
+    }
+    Ok(())
+}
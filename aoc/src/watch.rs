@@ -0,0 +1,267 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, Instant, SystemTime},
+};
+
+use aoc_core::{input, snapshot};
+
+use crate::{config::Config, solutions};
+
+/// how often the watched files are checked for changes
+const POLL: Duration = Duration::from_millis(200);
+
+/// the modification time of every file below the watched paths, missing paths are left out
+pub fn scan(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut files = BTreeMap::new();
+    let mut pending = paths.to_vec();
+
+    while let Some(path) = pending.pop() {
+        let Ok(metadata) = fs::metadata(&path) else {
+            continue;
+        };
+        if metadata.is_dir() {
+            if let Ok(entries) = fs::read_dir(&path) {
+                pending.extend(entries.flatten().map(|entry| entry.path()));
+            }
+        } else if let Ok(modified) = metadata.modified() {
+            files.insert(path, modified);
+        }
+    }
+    files
+}
+
+/// Waits until the files stop changing, so saving several files (or an editor writing a file
+/// in steps) runs the day once.
+#[derive(Debug)]
+pub struct Debounce {
+    quiet: Duration,
+    last_change: Option<Instant>,
+}
+
+impl Debounce {
+    pub fn new(quiet: Duration) -> Self {
+        Debounce {
+            quiet,
+            last_change: None,
+        }
+    }
+
+    /// records whether the files `changed` at `now`, true once they have been quiet long enough
+    /// after a change
+    pub fn settled(&mut self, changed: bool, now: Instant) -> bool {
+        if changed {
+            self.last_change = Some(now);
+            return false;
+        }
+        match self.last_change {
+            Some(last_change) if now.duration_since(last_change) >= self.quiet => {
+                self.last_change = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// what changed in the output of the binary since the previous run
+pub fn output_change(previous: Option<&str>, current: &str) -> String {
+    match previous {
+        None => current.trim_end().to_string(),
+        Some(previous) if previous == current => "the output did not change".to_string(),
+        Some(previous) => format!(
+            "the output changed (- previous, + now):\n{}",
+            snapshot::diff(previous, current)
+        ),
+    }
+}
+
+/// the binary of a part, `day-01-part-1` in 2022 and `part1` in 2023. The 2022 days share the
+/// root workspace, so their binaries are named after the day to keep them apart.
+fn bin_name(day_dir: &Path, day: u8, part: u8) -> String {
+    if day_dir
+        .join("src")
+        .join("bin")
+        .join(format!("part-{part}.rs"))
+        .exists()
+    {
+        format!("day-{day:02}-part-{part}")
+    } else {
+        format!("part{part}")
+    }
+}
+
+pub struct WatchOptions {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// passed on to the binary, see [`aoc_core::input::load`]
+    pub input: Option<String>,
+    pub quiet: Duration,
+}
+
+/// Runs the binary of a part and the tests of its day, and again every time the days sources or
+/// its input change. The days are built with cargo, so a change to a solution is picked up.
+pub fn watch(options: WatchOptions) -> Result<(), String> {
+    let day_dir = solutions::day_dir(options.year, options.day);
+    if !day_dir.exists() {
+        return Err(format!("there is no day at {}", day_dir.display()));
+    }
+    let config = Config::load(&solutions::config_path())?;
+    let manifest = day_dir.join("Cargo.toml");
+
+    let mut watched = vec![day_dir.join("src"), manifest.clone()];
+    match &options.input {
+        Some(path) if path != "-" => watched.push(PathBuf::from(path)),
+        Some(_) => return Err("stdin can not be watched, pass the input as a path".to_string()),
+        None => watched.extend(input::candidates(
+            Some(&config.input_dir),
+            &day_dir,
+            options.year,
+            options.day,
+            options.part,
+        )),
+    }
+
+    let run_bin = || {
+        let mut command = Command::new("cargo");
+        command
+            .args(["run", "-q", "--manifest-path"])
+            .arg(&manifest)
+            .args([
+                "--bin",
+                &bin_name(&day_dir, options.day, options.part),
+                "--",
+            ])
+            .args(&options.input)
+            .env(input::INPUT_DIR_VAR, &config.input_dir)
+            .stderr(Stdio::inherit());
+        command
+            .output()
+            .map(|output| String::from_utf8_lossy(&output.stdout).into_owned())
+            .map_err(|err| format!("could not run cargo: {err}"))
+    };
+    let run_tests = || {
+        Command::new("cargo")
+            .args(["test", "-q", "--manifest-path"])
+            .arg(&manifest)
+            .status()
+            .map_err(|err| format!("could not run cargo: {err}"))
+    };
+
+    let mut previous: Option<String> = None;
+    let mut files = scan(&watched);
+    let mut debounce = Debounce::new(options.quiet);
+    let mut reason = "watching".to_string();
+
+    loop {
+        println!(
+            "── {reason}: {} day {:02} part {} ──",
+            options.year, options.day, options.part
+        );
+        let output = run_bin()?;
+        println!("{}", output_change(previous.as_deref(), &output));
+        previous = Some(output);
+        if !run_tests()?.success() {
+            println!("the tests failed");
+        }
+
+        let mut changes: Vec<PathBuf> = vec![];
+        loop {
+            thread::sleep(POLL);
+            let now = scan(&watched);
+            let changed: Vec<PathBuf> = now
+                .iter()
+                .filter(|(path, modified)| files.get(*path) != Some(modified))
+                .map(|(path, _)| path.clone())
+                .chain(
+                    files
+                        .keys()
+                        .filter(|path| !now.contains_key(*path))
+                        .cloned(),
+                )
+                .collect();
+            files = now;
+
+            let any_change = !changed.is_empty();
+            changes.extend(changed);
+            if debounce.settled(any_change, Instant::now()) {
+                break;
+            }
+        }
+
+        changes.sort();
+        changes.dedup();
+        reason = format!(
+            "changed {}",
+            changes
+                .iter()
+                .map(|path| path
+                    .strip_prefix(&day_dir)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string())
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn debounces_rapid_changes() {
+        let start = Instant::now();
+        let at = |millis| start + Duration::from_millis(millis);
+        let mut debounce = Debounce::new(Duration::from_millis(300));
+
+        assert!(!debounce.settled(false, at(0)));
+        assert!(!debounce.settled(true, at(200)));
+        assert!(!debounce.settled(true, at(400)));
+        assert!(!debounce.settled(false, at(600)));
+        assert!(debounce.settled(false, at(700)));
+        assert!(!debounce.settled(false, at(1200)));
+    }
+
+    #[test]
+    fn scans_a_directory() {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src/bin")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+        fs::write(dir.join("src/bin/part-1.rs"), "").unwrap();
+
+        let files = scan(&[dir.join("src"), dir.join("input.txt")]);
+        assert_eq!(
+            files.keys().cloned().collect::<Vec<_>>(),
+            vec![dir.join("src/bin/part-1.rs"), dir.join("src/lib.rs")]
+        );
+        assert_eq!(bin_name(&dir, 9, 1), "day-09-part-1");
+        assert_eq!(bin_name(&dir, 9, 2), "part2");
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn output_changes() {
+        assert_eq!(
+            output_change(None, "Output part 01: 24000\n"),
+            "Output part 01: 24000"
+        );
+        assert_eq!(
+            output_change(Some("Output part 01: 24000\n"), "Output part 01: 24000\n"),
+            "the output did not change"
+        );
+        assert_eq!(
+            output_change(Some("Output part 01: 24000\n"), "Output part 01: 45000\n"),
+            "the output changed (- previous, + now):\n- Output part 01: 24000\n+ Output part 01: 45000"
+        );
+    }
+}
@@ -0,0 +1,9 @@
+use aoc_core::{input, Error};
+use {{crate_name}}::{process_input1, Part1};
+
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    println!("Output part 01: {}", process_input1(file)?);
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use aoc_core::{input, Error};
+use {{crate_name}}::{process_input2, Part2};
+
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
+    println!("Output part 02: {}", process_input2(file)?);
+
+    Ok(())
+}
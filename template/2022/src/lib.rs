@@ -0,0 +1,57 @@
+use aoc_core::{Error, Solution};
+
+pub fn process_input1(_file: String) -> Result<String, Error> {
+    todo!("day {{dd}} - part 1");
+}
+
+pub fn process_input2(_file: String) -> Result<String, Error> {
+    todo!("day {{dd}} - part 2");
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input1(input.to_string())
+    }
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        process_input2(input.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::tasks::assert_examples;
+
+    const TASKS: &str = include_str!("../../../tasks.md");
+
+    #[test]{{ignore_1}}
+    fn part_1() {
+        assert_examples::<Part1>(TASKS);
+    }
+
+    #[test]{{ignore_2}}
+    fn part_2() {
+        assert_examples::<Part2>(TASKS);
+    }
+}
@@ -0,0 +1,10 @@
+use aoc_core::{input, Error};
+use {{crate_name}}::part1::{solve, Part1};
+
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part1>(env!("CARGO_MANIFEST_DIR"))?;
+    let result = solve(&file)?;
+    println!("Part1: {result}");
+
+    Ok(())
+}
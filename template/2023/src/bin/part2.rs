@@ -0,0 +1,10 @@
+use aoc_core::{input, Error};
+use {{crate_name}}::part2::{solve, Part2};
+
+fn main() -> Result<(), Error> {
+    let file = input::load::<Part2>(env!("CARGO_MANIFEST_DIR"))?;
+    let result = solve(&file)?;
+    println!("Part2: {result}");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use aoc_core::{Error, Solution};
+
+pub fn solve(_input: &str) -> Result<String, Error> {
+    todo!("day {{dd}} - part 1");
+}
+
+pub struct Part1;
+
+impl Solution for Part1 {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const PART: u8 = 1;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() -> Result<(), Error> {
+        let input = {{example_input_1}};
+        let expected = {{example_answer_1}};
+        assert_eq!(expected, solve(input)?);
+        Ok(())
+    }
+}
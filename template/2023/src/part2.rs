@@ -0,0 +1,33 @@
+use aoc_core::{Error, Solution};
+
+pub fn solve(_input: &str) -> Result<String, Error> {
+    todo!("day {{dd}} - part 2");
+}
+
+pub struct Part2;
+
+impl Solution for Part2 {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+    const PART: u8 = 2;
+
+    type Params = ();
+    type Answer = String;
+
+    fn solve(input: &str, _params: &Self::Params) -> Result<Self::Answer, Error> {
+        solve(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() -> Result<(), Error> {
+        let input = {{example_input_2}};
+        let expected = {{example_answer_2}};
+        assert_eq!(expected, solve(input)?);
+        Ok(())
+    }
+}